@@ -0,0 +1,46 @@
+use std::{env, fs, path::Path};
+
+/// Turn every entry of `input/samples.toml` into its own test case,
+/// picked up by `tests/samples.rs`.
+fn main() {
+    let manifest = Path::new("input/samples.toml");
+    println!("cargo::rerun-if-changed={}", manifest.display());
+
+    let content = fs::read_to_string(manifest).unwrap_or_default();
+    let table: toml::Table = toml::from_str(&content).expect("Malformed input/samples.toml.");
+
+    let mut code = String::new();
+
+    for (day_key, inputs) in &table {
+        let day: u8 = day_key
+            .strip_prefix("day_")
+            .and_then(|x| x.parse().ok())
+            .expect("Keys should look like `day_05`.");
+
+        for (name, parts) in inputs.as_table().expect("Expected a table of inputs.") {
+            for (part_key, record) in parts.as_table().expect("Expected a table of parts.") {
+                let part: u8 = part_key
+                    .strip_prefix("part_")
+                    .and_then(|x| x.parse().ok())
+                    .expect("Keys should look like `part_1`.");
+
+                let Some(answer) = record.get("answer").and_then(|x| x.as_str()) else {
+                    continue;
+                };
+
+                let test_name: String = format!("{}_{}_{}", day_key, name, part_key)
+                    .chars()
+                    .map(|x| if x.is_ascii_alphanumeric() { x } else { '_' })
+                    .collect();
+
+                code.push_str(&format!(
+                    "#[test]\nfn {}() {{\n    check({}, {:?}, {}, {:?});\n}}\n\n",
+                    test_name, day, name, part, answer
+                ));
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("samples.rs");
+    fs::write(out, code).expect("Unable to write the generated tests.");
+}
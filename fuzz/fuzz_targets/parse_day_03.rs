@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_2025::{days::day_03::Day03, libs::solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|content: &str| {
+    let _ = Day03::parse(content);
+});
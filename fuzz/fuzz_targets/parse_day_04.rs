@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_2025::{days::day_04::Day04, libs::solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|content: &str| {
+    let _ = Day04::parse(content);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_2025::{days::day_05::Day05, libs::solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|content: &str| {
+    let _ = Day05::parse(content);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_2025::{days::day_06::Day06, libs::solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|content: &str| {
+    let _ = Day06::parse(content);
+});
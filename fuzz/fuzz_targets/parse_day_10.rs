@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_2025::{days::day_10::Day10, libs::solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|content: &str| {
+    let _ = Day10::parse(content);
+});
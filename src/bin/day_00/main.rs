@@ -1,125 +1,26 @@
-use num_bigint::BigUint;
-use regex::Regex;
-use std::fmt::{Display, Formatter};
-use std::{env, fs, path::MAIN_SEPARATOR, time::Instant};
-
-/// Get input file name based on the current running binary file.
-///
-/// For example, if running inside `src/bin/day_00/main.rs`,
-/// the function returns `input/day_00_[mode].txt`.
-///
-/// It's best to fold this code, btw.
-fn get_input_path(is_sample: bool) -> String {
-    // cargo test creates hash value in the resulting executable file.
-    let hash_regex = Regex::new("-[a-zA-Z0-9]+").unwrap();
-
-    let path = match env::current_exe() {
-        Ok(path) => String::from(path.to_str().expect("Expect a string?")),
-        Err(_e) => panic!("env::current_exe() failure!"),
-    };
-
-    let exe_name = path
-        .split(MAIN_SEPARATOR)
-        .next_back()
-        .expect("Seems like there isn't any slash?");
-
-    // Linux does not have . in file name.
-    let file_components: Vec<&str> = exe_name.split(".").collect();
-
-    let mut bin_name = exe_name;
-
-    if !file_components.is_empty() {
-        bin_name = file_components[0];
-    }
-
-    // clean up hash value, hopefully.
-    let bin_name = hash_regex.replace_all(bin_name, "");
-
-    let mut location = "input/".to_owned();
-    location.push_str(&bin_name);
-
-    if is_sample {
-        location.push_str("_sample");
-    } else {
-        location.push_str("_personal");
-    }
-
-    location.push_str(".txt");
-
-    location
-}
-
-/// The answer seems a little bit cute today?
-#[derive(Debug, PartialEq)]
-struct Umi {
-    answer: BigUint,
-}
-
-impl Display for Umi {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Feeling like {} because I'm cute :3", self.answer)
-    }
-}
+use aoc_2025::{days::day_00::Day00, libs::solution};
 
 #[forbid(unsafe_code)]
 fn main() {
-    let now = Instant::now();
-    println!("Part 1: {}", part_1(false));
-    let elapsed_time = now.elapsed();
-
-    println!("Running part_1() took {} ms.", elapsed_time.as_millis());
-
-    let now = Instant::now();
-    println!("Part 2: {}", part_2(false));
-    let elapsed_time = now.elapsed();
-
-    println!("Running part_2() took {} ms.", elapsed_time.as_millis());
-}
-
-#[forbid(unsafe_code)]
-fn part_1(is_sample: bool) -> Umi {
-    let path = get_input_path(is_sample);
-    let content = fs::read_to_string(path).expect("File read error.");
-
-    Umi {
-        answer: BigUint::from(4_u32),
-    }
-}
-
-#[forbid(unsafe_code)]
-fn part_2(is_sample: bool) -> Umi {
-    let path = get_input_path(is_sample);
-    let content = fs::read_to_string(path).expect("File read error.");
-
-    Umi {
-        answer: BigUint::from(8_u32),
-    }
+    solution::main::<Day00>();
 }
 
 /// Remember to edit the test.
 #[cfg(test)]
 mod aoc_test {
     use super::*;
-    use num_bigint::ToBigUint;
+    use aoc_2025::libs::{answer::Answer, solution::Solution};
     use parameterized::parameterized;
 
     #[parameterized(expected = { 4 })]
     fn result_part_1(expected: u128) {
-        assert_eq!(
-            part_1(true),
-            Umi {
-                answer: expected.to_biguint().unwrap()
-            }
-        )
+        let input = solution::load_file::<Day00>("sample");
+        assert_eq!(Day00::part_1(&input), Ok(Answer::from(expected)))
     }
 
     #[parameterized(expected = { 8 })]
     fn result_part_2(expected: u128) {
-        assert_eq!(
-            part_2(true),
-            Umi {
-                answer: expected.to_biguint().unwrap()
-            }
-        )
+        let input = solution::load_file::<Day00>("sample");
+        assert_eq!(Day00::part_2(&input), Ok(Answer::from(expected)))
     }
 }
@@ -1,156 +1,153 @@
-use std::{env, fs, path::MAIN_SEPARATOR, time::Instant};
-
-use geo::{Contains, LineString, Point, Polygon, Rect, point};
-use regex::Regex;
-
-/// Get input file name based on the current running binary file.
-///
-/// For example, if running inside `src/bin/day_00/main.rs`,
-/// the function returns `input/day_00_[mode].txt`.
-///
-/// It's best to fold this code, btw.
-fn get_input_path(is_sample: bool) -> String {
-    // cargo test creates hash value in the resulting executable file.
-    let hash_regex = Regex::new("-[a-zA-Z0-9]+").unwrap();
-
-    let path = match env::current_exe() {
-        Ok(path) => String::from(path.to_str().expect("Expect a string?")),
-        Err(_e) => panic!("env::current_exe() failure!"),
-    };
-
-    let exe_name = path
-        .split(MAIN_SEPARATOR)
-        .next_back()
-        .expect("Seems like there isn't any slash?");
-
-    // Linux does not have . in file name.
-    let file_components: Vec<&str> = exe_name.split(".").collect();
-
-    let mut bin_name = exe_name;
-
-    if !file_components.is_empty() {
-        bin_name = file_components[0];
-    }
-
-    // clean up hash value, hopefully.
-    let bin_name = hash_regex.replace_all(bin_name, "");
-
-    let mut location = "input/".to_owned();
-    location.push_str(&bin_name);
-
-    if is_sample {
-        location.push_str("_sample");
-    } else {
-        location.push_str("_personal");
-    }
-
-    location.push_str(".txt");
-
-    location
-}
+use aoc_2025::{days::day_09::Day09, libs::solution};
 
 #[forbid(unsafe_code)]
 fn main() {
-    let now = Instant::now();
-    println!("Part 1: {}", part_1(false));
-    let elapsed_time = now.elapsed();
-
-    println!("Running part_1() took {} ms.", elapsed_time.as_millis());
-
-    let now = Instant::now();
-    println!("Part 2: {}", part_2(false));
-    let elapsed_time = now.elapsed();
-
-    println!("Running part_2() took {} ms.", elapsed_time.as_millis());
+    solution::main::<Day09>();
 }
 
-#[forbid(unsafe_code)]
-fn part_1(is_sample: bool) -> i128 {
-    let path = get_input_path(is_sample);
-    let content = fs::read_to_string(path).expect("File read error.");
-
-    let mut points = vec![];
+/// Remember to edit the test.
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use aoc_2025::{
+        days::day_09::{self, Corners, Floor},
+        libs::{answer::Answer, poly, solution::Solution},
+    };
+    use parameterized::parameterized;
 
-    for line in content.lines() {
-        let items: Vec<&str> = line.split(",").collect();
-        let x: i128 = items[0].parse().expect("NaN");
-        let y: i128 = items[1].parse().expect("NaN");
-        points.push((x, y));
+    #[parameterized(expected = { 50 })]
+    fn result_part_1(expected: i128) {
+        let input = solution::load_file::<Day09>("sample");
+        assert_eq!(Day09::part_1(&input), Ok(Answer::from(expected)))
     }
 
-    let n = points.len();
-
-    let mut max_area = 0;
-
-    for i in 0..n {
-        for j in (i + 1)..n {
-            let (x1, y1) = points[i];
-            let (x2, y2) = points[j];
-
-            let diff_x = (x2).abs_diff(x1) as i128 + 1_i128;
-            let diff_y = (y2).abs_diff(y1) as i128 + 1_i128;
-
-            max_area = max_area.max(diff_x * diff_y);
-        }
+    #[parameterized(expected = { 24 })]
+    fn result_part_2(expected: u128) {
+        let input = solution::load_file::<Day09>("sample");
+        assert_eq!(Day09::part_2(&input), Ok(Answer::from(expected)))
     }
 
-    max_area
-}
-
-#[forbid(unsafe_code)]
-fn part_2(is_sample: bool) -> f64 {
-    let path = get_input_path(is_sample);
-    let content = fs::read_to_string(path).expect("File read error.");
-
-    let mut points: Vec<Point> = vec![];
+    #[test]
+    fn a_slot_without_tiles_is_no_gap() {
+        // a U, the slot between x = 2 and x = 3 has no tile, so 0,0 to 5,4 is all red or green.
+        let input = Day09::parse("0,0\n5,0\n5,4\n3,4\n3,1\n2,1\n2,4\n0,4\n").unwrap();
 
-    for line in content.lines() {
-        let items: Vec<&str> = line.split(",").collect();
-        let x: i128 = items[0].parse().expect("NaN");
-        let y: i128 = items[1].parse().expect("NaN");
-        points.push(point! { x: x as f64, y: y as f64 });
+        assert_eq!(Day09::part_2(&input), Ok(Answer::from(30)));
+        assert_eq!(Day09::reference_2(&input), Some(Answer::from(30)));
     }
 
-    let poly = Polygon::new(LineString::from(points.clone()), vec![]);
-
-    let n = points.len();
-
-    let mut max_area: f64 = 0_f64;
+    /// Every rectangle of tiles in the bounding box, checked tile by tile.
+    fn brute_force(corners: &[(i64, i64)], mode: Corners) -> u128 {
+        let (x_max, y_max) = corners
+            .iter()
+            .fold((0, 0), |m, &(x, y)| (m.0.max(x), m.1.max(y)));
+
+        let mut best = 0;
+
+        for x1 in 0..=x_max {
+            for x2 in x1..=x_max {
+                for y1 in 0..=y_max {
+                    for y2 in y1..=y_max {
+                        let red =
+                            [(x1, y1), (x2, y2), (x1, y2), (x2, y1)].map(|x| corners.contains(&x));
+
+                        let allowed = match mode {
+                            Corners::Red => (red[0] && red[1]) || (red[2] && red[3]),
+                            Corners::OneRed => red.contains(&true),
+                            Corners::Any => true,
+                        };
+
+                        let area = (x2 - x1 + 1) as u128 * (y2 - y1 + 1) as u128;
+
+                        if !allowed || area <= best {
+                            continue;
+                        }
+
+                        if (x1..=x2).all(|x| (y1..=y2).all(|y| poly::contains(corners, (x, y)))) {
+                            best = area;
+                        }
+                    }
+                }
+            }
+        }
 
-    for i in 0..n {
-        for j in (i + 1)..n {
-            let rect = Rect::new(points[i], points[j]);
+        best
+    }
 
-            let (x1, y1) = points[i].x_y();
-            let (x2, y2) = points[j].x_y();
+    #[parameterized(
+        corners = {
+            "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n",
+            "1,1\n4,1\n4,8\n9,8\n9,1\n12,1\n12,10\n1,10\n",
+            "2,0\n5,0\n5,4\n11,4\n11,9\n0,9\n0,6\n2,6\n",
+            "0,0\n3,0\n3,3\n6,3\n6,0\n9,0\n9,6\n6,6\n6,9\n3,9\n3,6\n0,6\n",
+        }
+    )]
+    fn matches_the_brute_force(corners: &str) {
+        let corners = Day09::parse(corners).unwrap();
+        let floor = Floor::new(&corners);
 
-            let diff_x = (x2 - x1).abs() + 1_f64;
-            let diff_y = (y2 - y1).abs() + 1_f64;
+        for mode in [Corners::Red, Corners::OneRed, Corners::Any] {
+            let area = floor.largest(mode).map_or(0, |x| x.area());
 
-            let area = diff_x * diff_y;
+            assert_eq!(area, brute_force(&corners, mode), "{:?}", mode);
+        }
+    }
 
-            if area > max_area && poly.contains(&rect) {
-                max_area = max_area.max(area);
-            }
+    #[parameterized(
+        corners = {
+            "0,0\n5,0\n5,4\n3,4\n3,1\n2,1\n2,4\n0,4\n",
+            "0,0\n7,0\n7,4\n5,4\n5,1\n4,1\n4,4\n3,4\n3,1\n2,1\n2,4\n0,4\n",
+            "0,0\n6,0\n6,4\n4,4\n4,1\n2,1\n2,4\n0,4\n",
+            "0,0\n4,0\n4,1\n1,1\n1,2\n4,2\n4,3\n0,3\n",
         }
+    )]
+    fn floor_is_part_2_where_edges_run_side_by_side(corners: &str) {
+        let corners = Day09::parse(corners).unwrap();
+        let area = Floor::new(&corners)
+            .largest(Corners::Red)
+            .map_or(0, |x| x.area());
+
+        assert_eq!(area, brute_force(&corners, Corners::Red));
+        assert_eq!(Day09::part_2(&corners), Ok(Answer::from(area)));
+        assert_eq!(Day09::reference_2(&corners), Some(Answer::from(area)));
     }
 
-    max_area
-}
+    #[test]
+    fn draws_the_loop_and_both_rectangles() {
+        let input = solution::load_file::<Day09>("sample");
+        let svg = day_09::svg(&input);
 
-/// Remember to edit the test.
-#[cfg(test)]
-mod aoc_test {
-    use super::*;
-    use parameterized::parameterized;
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        assert_eq!(svg.matches("<circle").count(), input.len());
+        assert!(svg.contains("Part 1: 2,1 to 11,5, 50 tiles"));
+        assert!(svg.contains("Part 2: 2,3 to 9,5, 24 tiles"));
+    }
 
-    #[parameterized(expected = { 50 })]
-    fn result_part_1(expected: i128) {
-        assert_eq!(part_1(true), expected)
+    #[test]
+    fn scales_big_coordinates_down() {
+        let svg = day_09::svg(&[(0, 0), (90_000, 0), (90_000, 30_000), (0, 30_000)]);
+
+        assert!(svg.contains("width=\"1040\" height=\"373\""));
     }
 
-    #[parameterized(expected = { 24_f64 })]
-    fn result_part_2(expected: f64) {
-        assert_eq!(part_2(true), expected)
+    #[test]
+    fn draws_what_the_parts_pick() {
+        for seed in 0..20 {
+            let content = Day09::generate(6, &mut aoc_2025::libs::generate::rng(seed)).unwrap();
+            let input = Day09::parse(&content).unwrap();
+            let svg = day_09::svg(&input);
+
+            for part in [1, 2] {
+                let rect = day_09::picked_by(&input, part).unwrap();
+                let title = format!("Part {}: {}, {} tiles", part, rect, rect.area());
+
+                assert!(svg.contains(&title), "{}", title);
+                assert_eq!(
+                    solution::part::<Day09>(&input, part),
+                    Ok(Answer::from(rect.area()))
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,28 @@
+use crate::libs::{
+    answer::Answer,
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    /// Parse input yourself.
+    type Input = String;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(_input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::from(4_u32))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(_input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::from(8_u32))
+    }
+}
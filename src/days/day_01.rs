@@ -0,0 +1,158 @@
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day01;
+
+/// Which way to turn the dial.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Rotation {
+    Left(i64),
+    Right(i64),
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Rotation>;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let mut chars = line.chars();
+                let direction = chars
+                    .next()
+                    .ok_or(ParseError::new("Empty line.").at_line(i))?;
+                let amount = chars
+                    .as_str()
+                    .parse::<i64>()
+                    .map_err(|e| ParseError::new(format!("Bad amount, {}.", e)).at(i, 1))?;
+
+                if amount < 0 {
+                    return Err(ParseError::new("Negative amount.").at(i, 1));
+                }
+
+                // the dial position plus an amount has to fit.
+                if amount > 1_000_000_000 {
+                    return Err(ParseError::new("Amounts go up to a billion.").at(i, 1));
+                }
+
+                match direction {
+                    'L' => Ok(Rotation::Left(amount)),
+                    'R' => Ok(Rotation::Right(amount)),
+                    _ => Err(ParseError::new("Not a valid direction.").at(i, 0)),
+                }
+            })
+            .collect()
+    }
+
+    /// Most of AoC problems use uint as output.
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut pos = 50;
+        let mut count = 0_usize;
+
+        for rotation in input {
+            match *rotation {
+                Rotation::Left(amount) => {
+                    pos = (pos - amount) % 100;
+                }
+                Rotation::Right(amount) => {
+                    pos = (pos + amount) % 100;
+                }
+            }
+
+            if pos == 0 {
+                count += 1;
+            }
+        }
+
+        Ok(Answer::from(count))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut pos = 50;
+        let mut count = 0_i64;
+
+        for rotation in input {
+            let (Rotation::Left(amount) | Rotation::Right(amount)) = *rotation;
+
+            count += amount / 100;
+            let amount = amount % 100;
+
+            match rotation {
+                Rotation::Left(_) => {
+                    if pos != 0 && pos - amount <= 0 {
+                        count += 1;
+                    }
+
+                    pos = (pos - amount).rem_euclid(100);
+                }
+                Rotation::Right(_) => {
+                    if pos != 0 && pos + amount >= 100 {
+                        count += 1;
+                    }
+
+                    pos = (pos + amount).rem_euclid(100);
+                }
+            }
+        }
+
+        Ok(Answer::from(count))
+    }
+
+    /// One click at a time.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(clicks(input).1))
+    }
+
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(clicks(input).0))
+    }
+
+    /// `size` rotations.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let rotations = (0..size.max(1)).map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            format!("{}{}", direction, rng.random_range(1..1000))
+        });
+
+        Some(generate::lines(rotations.collect::<Vec<_>>()))
+    }
+}
+
+/// Turn the dial one click at a time: how often it passes 0, how often a rotation ends on 0.
+fn clicks(input: &[Rotation]) -> (usize, usize) {
+    let mut pos = 50;
+    let mut passes = 0;
+    let mut stops = 0;
+
+    for rotation in input {
+        let (step, amount) = match *rotation {
+            Rotation::Left(amount) => (99, amount),
+            Rotation::Right(amount) => (1, amount),
+        };
+
+        for _ in 0..amount {
+            pos = (pos + step) % 100;
+
+            if pos == 0 {
+                passes += 1;
+            }
+        }
+
+        if pos == 0 {
+            stops += 1;
+        }
+    }
+
+    (passes, stops)
+}
@@ -0,0 +1,150 @@
+use rand::{RngExt, seq::SliceRandom};
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// Inclusive ID ranges.
+    type Input = Vec<(i64, i64)>;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim_end()
+            .split(",")
+            .map(|range| {
+                let (head, tail) = range
+                    .trim()
+                    .split_once("-")
+                    .ok_or_else(|| ParseError::new(format!("No dash in {:?}.", range)))?;
+
+                let number = |x: &str| {
+                    x.parse::<i64>()
+                        .map_err(|e| ParseError::new(format!("Bad ID {:?}, {}.", x, e)))
+                };
+
+                Ok((number(head)?, number(tail)?))
+            })
+            .collect()
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut result = 0_i64;
+
+        for &(head, tail) in input {
+            for i in head..=tail {
+                let x = i.to_string();
+                let l = x.len();
+
+                if l % 2 != 0 {
+                    continue;
+                }
+
+                let first_half = &x[..(l / 2)];
+                let second_half = &x[(l / 2)..];
+
+                if first_half == second_half {
+                    result += i;
+                }
+            }
+        }
+
+        Ok(Answer::from(result))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut result = 0_i64;
+
+        for &(head, tail) in input {
+            for i in head..=tail {
+                let x = i.to_string();
+                let l = x.len();
+                let mut has_match = false;
+
+                for len in 1..l {
+                    if l % len != 0 {
+                        continue;
+                    }
+
+                    let part = &x[..len];
+
+                    let count = x
+                        .as_bytes()
+                        .chunks(len)
+                        .filter(|&x| x == part.as_bytes())
+                        .count();
+
+                    if count * len == l {
+                        has_match = true;
+                        break;
+                    }
+                }
+
+                if has_match {
+                    result += i;
+                }
+            }
+        }
+
+        Ok(Answer::from(result))
+    }
+
+    /// Compare each ID with its repeated prefix.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(sum_invalid(input, |_| vec![2])))
+    }
+
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(sum_invalid(input, |l| (2..=l).collect())))
+    }
+
+    /// `size` disjoint ranges, each at most a thousand IDs wide, on one line.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let mut ranges = vec![];
+        let mut cursor: i64 = rng.random_range(1..100);
+
+        for _ in 0..size.max(1) {
+            // the gaps grow with the numbers, so IDs of every length show up.
+            let head = cursor + rng.random_range(0..=cursor.min(100_000_000_000));
+            let tail = head + rng.random_range(0..=1000);
+
+            ranges.push(format!("{}-{}", head, tail));
+            cursor = tail + 1;
+        }
+
+        ranges.shuffle(rng);
+
+        Some(generate::lines([ranges.join(",")]))
+    }
+}
+
+/// Sum of the IDs made of `k` copies of their first `len / k` digits, for any `k` in `repeats(len)`
+/// that divides `len`.
+fn sum_invalid<F: Fn(usize) -> Vec<usize>>(input: &[(i64, i64)], repeats: F) -> i64 {
+    let mut result = 0;
+
+    for &(head, tail) in input {
+        for i in head..=tail {
+            let x = i.to_string();
+            let l = x.len();
+
+            if repeats(l)
+                .iter()
+                .any(|&k| l % k == 0 && x == x[..l / k].repeat(k))
+            {
+                result += i;
+            }
+        }
+    }
+
+    result
+}
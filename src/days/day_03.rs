@@ -0,0 +1,193 @@
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day03;
+
+/// NSFW warning?
+#[derive(Debug, Copy, Clone)]
+struct Umipai {
+    digit: u32,
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    /// Joltage digits, one bank per line.
+    type Input = Vec<Vec<u32>>;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if line.is_empty() {
+                    return Err(ParseError::new("Empty bank.").at_line(i));
+                }
+
+                line.chars()
+                    .enumerate()
+                    .map(|(j, x)| match x.to_digit(10) {
+                        // batteries are rated 1 to 9, part 1 reads a 0 as nothing picked.
+                        Some(0) => Err(ParseError::new("Joltages go from 1 to 9.").at(i, j)),
+                        Some(x) => Ok(x),
+                        None => Err(ParseError::new(format!("{:?} is not a digit.", x)).at(i, j)),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut total: usize = 0;
+
+        for numbers in input {
+            // basically, a linear function mx + b, at its maxima when m and b reaches max.
+
+            let index_max_1: usize = numbers
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.cmp(b))
+                .map(|(index, _)| index)
+                .expect("Hmmmm.");
+
+            let max_1 = numbers[index_max_1];
+
+            let mut max_left = u32::MIN;
+            let mut max_right = u32::MIN;
+
+            for left_i in numbers.iter().take(index_max_1) {
+                max_left = max_left.max(*left_i);
+            }
+
+            for right_i in numbers.iter().skip(index_max_1 + 1) {
+                max_right = max_right.max(*right_i);
+            }
+
+            let mut result1 = max_left * 10 + max_1;
+            let mut result2 = max_1 * 10 + max_right;
+
+            if max_left == 0 {
+                result1 = 0;
+            }
+
+            if max_right == 0 {
+                result2 = 0;
+            }
+
+            let volt = u32::max(result1, result2);
+
+            total += volt as usize;
+        }
+
+        Ok(Answer::from(total))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        // can't believe we jumped from 2 to 12, smh.
+        let mut total: i128 = 0;
+
+        // basically, try to form the longest number chain possible,
+        // like 9999..9, 999..8, ... and so on.
+        // https://en.wikipedia.org/wiki/Tournament_sort
+        // (sans the min-heap part).
+        for numbers in input {
+            let mut arr: Vec<Umipai> = vec![];
+
+            for (i, v) in numbers.iter().enumerate() {
+                let num = *v;
+                let obj = Umipai { digit: num };
+
+                loop {
+                    // for some reasons, leave this code above the `loop` doesn't work.
+                    if arr.is_empty() {
+                        arr.push(obj);
+                        break;
+                        // continue;
+                    }
+
+                    let unused_number_count = numbers.len() - i;
+                    let slot_count = 12 - arr.len();
+                    let last = *arr.last().unwrap();
+
+                    // basically, clean up small numbers so that
+                    // the larger number (champion) will join the inner bracket.
+                    // to create a chain of 9's, then 8's, ...
+                    if last.digit < num && unused_number_count > slot_count {
+                        arr.pop();
+                        continue;
+                    }
+
+                    // then the champion joins the bracket.
+                    if slot_count > 0 {
+                        arr.push(Umipai { digit: num });
+                        break;
+                    } else {
+                        break;
+                    }
+                }
+            }
+
+            if arr.is_empty() {
+                panic!("Why are you panic?")
+            }
+
+            let number: i128 = arr
+                .iter()
+                .fold(0, |umi, meow| umi * 10 + meow.digit as i128);
+
+            total += number;
+        }
+
+        Ok(Answer::from(total))
+    }
+
+    /// Every way to pick the digits, a DP over the bank.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(input.iter().map(|x| best(x, 2)).sum::<i128>()))
+    }
+
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(
+            input.iter().map(|x| best(x, x.len().min(12))).sum::<i128>(),
+        ))
+    }
+
+    /// `size` banks of 12 to 100 batteries.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let banks = (0..size.max(1)).map(|_| {
+            let len = rng.random_range(12..=12 + size.min(88));
+
+            (0..len)
+                .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                .collect::<String>()
+        });
+
+        Some(generate::lines(banks.collect::<Vec<_>>()))
+    }
+}
+
+/// The largest number made of `k` digits of `bank`, kept in order.
+fn best(bank: &[u32], k: usize) -> i128 {
+    // best[j] is the largest number of j digits picked so far, if any.
+    let mut best: Vec<Option<i128>> = vec![None; k + 1];
+    best[0] = Some(0);
+
+    for &digit in bank {
+        for j in (1..=k).rev() {
+            if let Some(x) = best[j - 1] {
+                let candidate = x * 10 + digit as i128;
+                best[j] = best[j].max(Some(candidate));
+            }
+        }
+    }
+
+    best[k].unwrap_or(0)
+}
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    /// `@` is a paper roll, `.` is nothing.
+    type Input = Vec<Vec<char>>;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid: Vec<Vec<char>> = input.lines().map(|x| x.chars().collect()).collect();
+
+        if grid.is_empty() {
+            return Err(ParseError::new("Empty grid."));
+        }
+
+        for (i, row) in grid.iter().enumerate() {
+            if row.len() != grid[0].len() {
+                return Err(ParseError::new("Rows are not the same length.").at_line(i));
+            }
+
+            if let Some(j) = row.iter().position(|x| *x != '@' && *x != '.') {
+                return Err(ParseError::new(format!("{:?} is not a tile.", row[j])).at(i, j));
+            }
+        }
+
+        Ok(grid)
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let mut paper_rolls = 0;
+
+        let rows = grid.len() as i32;
+        let cols = grid[0].len() as i32;
+
+        let is_a_paper_roll = |r: i32, c: i32| {
+            0 <= r && r < rows && 0 <= c && c < cols && grid[r as usize][c as usize] == '@'
+        };
+
+        for i in 0..rows {
+            for j in 0..cols {
+                let mut count = 0;
+
+                if grid[i as usize][j as usize] == '.' {
+                    continue;
+                }
+
+                // 12h
+                if is_a_paper_roll(i - 1, j) {
+                    count += 1;
+                }
+
+                // 1h30
+                if is_a_paper_roll(i - 1, j + 1) {
+                    count += 1;
+                }
+
+                // 3h
+                if is_a_paper_roll(i, j + 1) {
+                    count += 1;
+                }
+
+                // 4h30
+                if is_a_paper_roll(i + 1, j + 1) {
+                    count += 1;
+                }
+
+                // 6h
+                if is_a_paper_roll(i + 1, j) {
+                    count += 1;
+                }
+
+                // 7h30
+                if is_a_paper_roll(i + 1, j - 1) {
+                    count += 1;
+                }
+
+                // 9h
+                if is_a_paper_roll(i, j - 1) {
+                    count += 1;
+                }
+
+                // 10h30
+                if is_a_paper_roll(i - 1, j - 1) {
+                    count += 1;
+                }
+
+                if count < 4 {
+                    paper_rolls += 1
+                }
+            }
+        }
+
+        Ok(Answer::from(paper_rolls))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(grid: &Self::Input) -> Result<Answer, SolveError> {
+        let mut ignore_list: HashSet<(i32, i32)> = HashSet::new();
+
+        let mut destroy_count = 0;
+
+        loop {
+            let mut paper_rolls = 0;
+
+            let rows = grid.len() as i32;
+            let cols = grid[0].len() as i32;
+
+            let is_a_paper_roll = |v: &Vec<Vec<char>>, i: &HashSet<(i32, i32)>, r: i32, c: i32| {
+                0 <= r
+                    && r < rows
+                    && 0 <= c
+                    && c < cols
+                    && !i.contains(&(r, c))
+                    && v[r as usize][c as usize] == '@'
+            };
+
+            for i in 0..rows {
+                for j in 0..cols {
+                    let mut count = 0;
+
+                    if ignore_list.contains(&(i, j)) {
+                        continue;
+                    }
+
+                    if grid[i as usize][j as usize] == '.' {
+                        ignore_list.insert((i, j));
+                        continue;
+                    }
+
+                    // 12h
+                    if is_a_paper_roll(grid, &ignore_list, i - 1, j) {
+                        count += 1;
+                    }
+
+                    // 1h30
+                    if is_a_paper_roll(grid, &ignore_list, i - 1, j + 1) {
+                        count += 1;
+                    }
+
+                    // 3h
+                    if is_a_paper_roll(grid, &ignore_list, i, j + 1) {
+                        count += 1;
+                    }
+
+                    // 4h30
+                    if is_a_paper_roll(grid, &ignore_list, i + 1, j + 1) {
+                        count += 1;
+                    }
+
+                    // 6h
+                    if is_a_paper_roll(grid, &ignore_list, i + 1, j) {
+                        count += 1;
+                    }
+
+                    // 7h30
+                    if is_a_paper_roll(grid, &ignore_list, i + 1, j - 1) {
+                        count += 1;
+                    }
+
+                    // 9h
+                    if is_a_paper_roll(grid, &ignore_list, i, j - 1) {
+                        count += 1;
+                    }
+
+                    // 10h30
+                    if is_a_paper_roll(grid, &ignore_list, i - 1, j - 1) {
+                        count += 1;
+                    }
+
+                    if count < 4 {
+                        paper_rolls += 1;
+                        ignore_list.insert((i, j));
+                    }
+                }
+            }
+
+            if paper_rolls == 0 {
+                break;
+            } else {
+                destroy_count += paper_rolls;
+            }
+        }
+
+        Ok(Answer::from(destroy_count))
+    }
+
+    /// Count the neighbours of every roll, remove all the loose ones at once, repeat.
+    fn reference_1(grid: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(loose_rolls(grid).len()))
+    }
+
+    fn reference_2(grid: &Self::Input) -> Option<Answer> {
+        let mut grid = grid.clone();
+        let mut removed = 0;
+
+        loop {
+            let loose = loose_rolls(&grid);
+
+            if loose.is_empty() {
+                break;
+            }
+
+            removed += loose.len();
+
+            for (i, j) in loose {
+                grid[i][j] = '.';
+            }
+        }
+
+        Some(Answer::from(removed))
+    }
+
+    /// A `size` by `size` grid, a bit more than half of it rolls.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(1);
+        let rows = (0..size).map(|_| {
+            (0..size)
+                .map(|_| if rng.random_bool(0.6) { '@' } else { '.' })
+                .collect::<String>()
+        });
+
+        Some(generate::lines(rows.collect::<Vec<_>>()))
+    }
+}
+
+/// Rolls with fewer than 4 rolls around them.
+fn loose_rolls(grid: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let mut loose = vec![];
+
+    for i in 0..grid.len() {
+        for j in 0..grid[i].len() {
+            if grid[i][j] != '@' {
+                continue;
+            }
+
+            let count = (i.saturating_sub(1)..=i + 1)
+                .flat_map(|r| (j.saturating_sub(1)..=j + 1).map(move |c| (r, c)))
+                .filter(|&(r, c)| (r, c) != (i, j))
+                .filter(|&(r, c)| grid.get(r).and_then(|x| x.get(c)) == Some(&'@'))
+                .count();
+
+            if count < 4 {
+                loose.push((i, j));
+            }
+        }
+    }
+
+    loose
+}
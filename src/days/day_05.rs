@@ -0,0 +1,195 @@
+use std::{collections::BTreeSet, io::BufRead};
+
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    interval::IntervalTree,
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day05;
+
+/// The ingredient database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Database {
+    /// Inclusive ranges of fresh IDs, in file order.
+    pub ranges: Vec<(i64, i64)>,
+    pub ids: Vec<i64>,
+    /// Built from `ranges` by [`Solution::prepare`].
+    pub fresh: IntervalTree,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Database;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut reading_actual_input = false;
+        let mut ranges = vec![];
+        let mut ids = vec![];
+
+        let number = |x: &str, i: usize| {
+            x.parse::<i64>()
+                .map_err(|e| ParseError::new(format!("Bad ID {:?}, {}.", x, e)).at_line(i))
+        };
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                reading_actual_input = true;
+                continue;
+            }
+
+            if !reading_actual_input {
+                let (head, tail) = line
+                    .split_once("-")
+                    .ok_or_else(|| ParseError::new("No dash in range.").at_line(i))?;
+
+                ranges.push((number(head, i)?, number(tail, i)?));
+            } else {
+                ids.push(number(line, i)?);
+            }
+        }
+
+        if ranges.is_empty() {
+            return Err(ParseError::new("No fresh ranges."));
+        }
+
+        Ok(Database {
+            ranges,
+            ids,
+            fresh: IntervalTree::default(),
+        })
+    }
+
+    fn prepare(input: &mut Self::Input) {
+        input.fresh = IntervalTree::new(&input.ranges);
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::from(input.fresh.count_contained(&input.ids)))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::from(input.fresh.total()))
+    }
+
+    /// Check every range for every ID.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        let count = input
+            .ids
+            .iter()
+            .filter(|&&x| input.ranges.iter().any(|&(a, b)| (a..=b).contains(&x)))
+            .count();
+
+        Some(Answer::from(count))
+    }
+
+    /// Put every fresh ID in a set, fine for small ranges only.
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        let fresh: BTreeSet<i64> = input.ranges.iter().flat_map(|&(a, b)| a..=b).collect();
+
+        Some(Answer::from(fresh.len()))
+    }
+
+    /// `size` fresh ranges, then `size` IDs, overlaps included.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(1);
+        let limit = 1000 * size as i64;
+        let mut lines = vec![];
+
+        for _ in 0..size {
+            let head = rng.random_range(1..=limit);
+            let tail = head + rng.random_range(0..=limit / 10);
+            lines.push(format!("{}-{}", head, tail));
+        }
+
+        lines.push(String::new());
+
+        for _ in 0..size {
+            lines.push(rng.random_range(1..=limit + limit / 10).to_string());
+        }
+
+        Some(generate::lines(lines))
+    }
+}
+
+/// Both answers without holding the IDs in memory, only the merged ranges.
+///
+/// Same format as [`Solution::parse`] takes, a BOM and CRLF line endings are fine.
+pub fn stream<R: BufRead>(mut reader: R) -> Result<(usize, u128), ParseError> {
+    let mut line = String::new();
+    let mut i = 0;
+    let mut ranges = vec![];
+
+    let number = |x: &str, i: usize| {
+        x.parse::<i64>()
+            .map_err(|e| ParseError::new(format!("Bad ID {:?}, {}.", x, e)).at_line(i))
+    };
+
+    let mut first = true;
+
+    // one line at a time into the same buffer, `None` at the end.
+    let mut next = |line: &mut String| -> Result<Option<()>, ParseError> {
+        line.clear();
+
+        let read = reader
+            .read_line(line)
+            .map_err(|e| ParseError::new(format!("Unable to read, {}.", e)))?;
+
+        if read == 0 {
+            return Ok(None);
+        }
+
+        let end = line.trim_end_matches(['\r', '\n']).len();
+        line.truncate(end);
+
+        // only the file starts with a BOM, same as the whole input.
+        if std::mem::take(&mut first)
+            && let Some(rest) = line.strip_prefix('\u{feff}')
+        {
+            *line = rest.to_owned();
+        }
+
+        Ok(Some(()))
+    };
+
+    while next(&mut line)?.is_some() {
+        if line.is_empty() {
+            break;
+        }
+
+        let (head, tail) = line
+            .split_once("-")
+            .ok_or_else(|| ParseError::new("No dash in range.").at_line(i))?;
+
+        ranges.push((number(head, i)?, number(tail, i)?));
+        i += 1;
+    }
+
+    if ranges.is_empty() {
+        return Err(ParseError::new("No fresh ranges."));
+    }
+
+    let fresh = IntervalTree::new(&ranges);
+    drop(ranges);
+
+    let mut count = 0;
+    i += 1;
+
+    while next(&mut line)?.is_some() {
+        // blank lines at the end of the file.
+        if !line.is_empty() && fresh.contains(number(&line, i)?) {
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    Ok((count, fresh.total()))
+}
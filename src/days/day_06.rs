@@ -0,0 +1,306 @@
+use num_bigint::BigUint;
+
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    /// The worksheet as is, part 2 reads it column by column
+    /// so trailing spaces matter.
+    type Input = Vec<String>;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // part 2 walks the columns of every line, so they all get the same width.
+        let width = input.lines().map(|x| x.len()).max().unwrap_or(0);
+        let lines: Vec<String> = input
+            .lines()
+            .map(|x| format!("{:<width$}", x, width = width))
+            .collect();
+
+        if lines.len() < 2 {
+            return Err(ParseError::new("Expect numbers and a line of operators."));
+        }
+
+        // part 2 stacks one digit per line into a u32, 10 lines of 9s would not fit.
+        if lines.len() > 10 {
+            return Err(ParseError::new("Expect at most 9 lines of numbers."));
+        }
+
+        let last = lines.len() - 1;
+
+        if let Some(j) = lines[last]
+            .chars()
+            .position(|x| !matches!(x, '+' | '*' | ' '))
+        {
+            return Err(ParseError::new("Not an operator.").at(last, j));
+        }
+
+        for (i, line) in lines.iter().enumerate().take(last) {
+            if let Some(j) = line.chars().position(|x| !x.is_ascii_digit() && x != ' ') {
+                return Err(ParseError::new("Not a digit.").at(i, j));
+            }
+        }
+
+        let problems = lines[last].split_whitespace().count();
+
+        for (i, line) in lines.iter().enumerate().take(last) {
+            let numbers: Vec<&str> = line.split_whitespace().collect();
+
+            if numbers.len() != problems {
+                return Err(ParseError::new(format!(
+                    "Expect {} numbers, one per operator.",
+                    problems
+                ))
+                .at_line(i));
+            }
+
+            // part 1 reads each number as an i32, 10 digits can go past i32::MAX.
+            if numbers.iter().any(|x| x.len() > 9) {
+                return Err(ParseError::new("Expect at most 9 digits.").at_line(i));
+            }
+        }
+
+        Ok(lines)
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut total: BigUint = BigUint::ZERO;
+
+        let mut lines: Vec<Vec<&str>> = vec![];
+
+        for line in input {
+            let line: Vec<&str> = line.split(" ").collect();
+
+            let filtered_empty: Vec<&str> = line.into_iter().filter(|x| !x.is_empty()).collect();
+
+            lines.push(filtered_empty);
+        }
+
+        let lc = lines.len();
+
+        let operands = lines.last().expect("No last element?");
+        let numbers: Vec<&Vec<&str>> = lines.iter().take(lc - 1).collect();
+
+        let width = lines[0].len();
+        let height = lc - 1;
+
+        for w in 0..width {
+            let mut val = if operands[w] == "*" {
+                BigUint::from(1_u32)
+            } else {
+                BigUint::ZERO
+            };
+            let is_mul = operands[w] == "*";
+
+            for number in numbers.iter().take(height) {
+                let num = number[w].parse::<i32>().expect("NaN");
+                let num = BigUint::from(num as u32);
+
+                if is_mul {
+                    val *= num;
+                } else {
+                    val += num;
+                }
+            }
+
+            total += val;
+        }
+
+        Ok(Answer::from(total))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut total: BigUint = BigUint::ZERO;
+
+        let lines = input;
+
+        let height = lines.len();
+        let width = lines[0].len();
+
+        let mut numbers: Vec<u32> = vec![];
+
+        for w in (0..width).rev() {
+            let number_count = height - 1;
+            let mut parsed_num = 0;
+
+            for line in lines.iter().take(number_count) {
+                let character = line.chars().nth(w).expect("Huh?");
+
+                if character != ' ' {
+                    parsed_num = parsed_num * 10 + character.to_digit(10).expect("NaN");
+                }
+            }
+
+            if parsed_num == 0 {
+                numbers.clear();
+                continue;
+            }
+
+            numbers.push(parsed_num);
+
+            let operand = lines[height - 1]
+                .chars()
+                .nth(w)
+                .expect("Did you turn off whitespace trim?");
+
+            if operand != ' ' {
+                let mut val = if operand == '*' {
+                    BigUint::from(1_u32)
+                } else {
+                    BigUint::ZERO
+                };
+                let is_mul = operand == '*';
+
+                for number in numbers.iter() {
+                    if is_mul {
+                        val *= BigUint::from(*number);
+                    } else {
+                        val += BigUint::from(*number);
+                    }
+                }
+
+                total += val;
+            }
+        }
+
+        Ok(Answer::from(total))
+    }
+
+    /// Cut the padded sheet at the blank columns, then read each problem by rows.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        let total = problems(input)
+            .into_iter()
+            .map(|(rows, _, operator)| solve(&rows, operator))
+            .sum::<BigUint>();
+
+        Some(Answer::from(total))
+    }
+
+    /// Same cut, read by columns.
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        let total = problems(input)
+            .into_iter()
+            .map(|(_, columns, operator)| solve(&columns, operator))
+            .sum::<BigUint>();
+
+        Some(Answer::from(total))
+    }
+
+    /// `size` problems of 2 to 4 numbers, each up to 4 digits and aligned either way.
+    ///
+    /// Digits are 1 to 9, like the puzzle's.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let rows = rng.random_range(2..=4);
+        let mut lines = vec![String::new(); rows + 1];
+
+        for problem in 0..size.max(1) {
+            if problem > 0 {
+                for line in lines.iter_mut() {
+                    line.push(' ');
+                }
+            }
+
+            let width = rng.random_range(1..=4);
+            // someone has to fill the whole column.
+            let widest = rng.random_range(0..rows);
+
+            for (i, line) in lines.iter_mut().take(rows).enumerate() {
+                let len = if i == widest {
+                    width
+                } else {
+                    rng.random_range(1..=width)
+                };
+
+                let number: String = (0..len)
+                    .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                    .collect();
+
+                if rng.random_bool(0.5) {
+                    line.push_str(&format!("{:<width$}", number, width = width));
+                } else {
+                    line.push_str(&format!("{:>width$}", number, width = width));
+                }
+            }
+
+            let operator = if rng.random_bool(0.5) { "*" } else { "+" };
+            lines[rows].push_str(&format!("{:<width$}", operator, width = width));
+        }
+
+        Some(generate::lines(lines))
+    }
+}
+
+/// Every problem of the sheet: its numbers read by rows, read by columns, and its operator.
+fn problems(input: &[String]) -> Vec<(Vec<String>, Vec<String>, char)> {
+    let width = input.iter().map(|x| x.len()).max().unwrap_or(0);
+    let grid: Vec<Vec<char>> = input
+        .iter()
+        .map(|x| format!("{:<width$}", x, width = width).chars().collect())
+        .collect();
+
+    let (numbers, operators) = grid.split_at(grid.len() - 1);
+    let blank = |x: usize| grid.iter().all(|row| row[x] == ' ');
+
+    let mut problems = vec![];
+    let mut x = 0;
+
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+
+        while x < width && !blank(x) {
+            x += 1;
+        }
+
+        let rows = numbers
+            .iter()
+            .map(|row| row[start..x].iter().collect::<String>().trim().to_owned())
+            .collect();
+        let columns = (start..x)
+            .map(|c| {
+                numbers
+                    .iter()
+                    .map(|row| row[c])
+                    .filter(|&d| d != ' ')
+                    .collect()
+            })
+            .collect();
+        let operator = operators[0][start..x]
+            .iter()
+            .copied()
+            .find(|&c| c != ' ')
+            .unwrap_or('+');
+
+        problems.push((rows, columns, operator));
+    }
+
+    problems
+}
+
+fn solve(numbers: &[String], operator: char) -> BigUint {
+    let numbers = numbers
+        .iter()
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse::<BigUint>().expect("Digits only."));
+
+    if operator == '*' {
+        numbers.product()
+    } else {
+        numbers.sum()
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+
+use num_bigint::BigUint;
+
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    /// The manifold, `S` on the first line and splitters `^` on every other line after.
+    type Input = Vec<String>;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+
+        if !lines.first().is_some_and(|x| x.contains('S')) {
+            return Err(ParseError::new("No S on the first line.").at_line(0));
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(j) = line.chars().position(|x| !matches!(x, '.' | '^' | 'S')) {
+                return Err(ParseError::new("Not a tile.").at(i, j));
+            }
+
+            // a splitter on the edge would send the beam off the manifold.
+            if line.starts_with('^') || line.ends_with('^') {
+                return Err(ParseError::new("Splitter on the edge.").at_line(i));
+            }
+        }
+
+        Ok(lines)
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut require_x = HashSet::new();
+
+        let lines = input.iter();
+
+        for line in lines.clone().take(1) {
+            for (x, chr) in line.chars().enumerate() {
+                if chr == 'S' {
+                    require_x.insert(x);
+                    break;
+                }
+            }
+        }
+
+        let mut total = BigUint::ZERO;
+
+        for line in lines.skip(2).step_by(2) {
+            let mut processed_x = HashSet::new();
+            let mut next_x_require = HashSet::new();
+
+            for (x, chr) in line.chars().enumerate() {
+                if chr == '^' && require_x.contains(&x) {
+                    processed_x.insert(x);
+                    total += BigUint::from(1_u32);
+
+                    next_x_require.insert(x - 1);
+                    next_x_require.insert(x + 1);
+                }
+            }
+
+            for v in processed_x {
+                require_x.remove(&v);
+            }
+
+            for v in next_x_require {
+                require_x.insert(v);
+            }
+        }
+
+        Ok(Answer::from(total))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        // how many rays to reach x
+        let mut rays: HashMap<usize, BigUint> = HashMap::new();
+
+        let lines = input.iter();
+
+        for line in lines.clone().take(1) {
+            for (x, chr) in line.chars().enumerate() {
+                if chr == 'S' {
+                    rays.insert(x, BigUint::from(1_u32));
+                    break;
+                }
+            }
+        }
+
+        for line in lines.skip(2).step_by(2) {
+            let mut next_rays = HashMap::new();
+
+            for (x, chr) in line.chars().enumerate() {
+                if chr == '^' {
+                    for (k, v) in &rays {
+                        // branch if we see a spiltter.
+                        if *k == x {
+                            next_rays
+                                .entry(x + 1)
+                                .and_modify(|umeow| *umeow += v)
+                                .or_insert(v.clone());
+
+                            next_rays
+                                .entry(x - 1)
+                                .and_modify(|umeow| *umeow += v)
+                                .or_insert(v.clone());
+                        }
+                    }
+                } else {
+                    for (k, v) in &rays {
+                        if *k == x {
+                            // or else, we go down.
+                            next_rays
+                                .entry(x)
+                                .and_modify(|umeow| *umeow += v)
+                                .or_insert(v.clone());
+                        }
+                    }
+                }
+            }
+
+            rays = next_rays;
+        }
+
+        Ok(Answer::from(rays.values().sum::<BigUint>()))
+    }
+
+    /// Follow the beams through every row, odd ones included.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(beams(input).0))
+    }
+
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        Some(Answer::from(beams(input).1))
+    }
+
+    /// `size` rows of splitters, never on the edge nor next to each other.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(1);
+        let width = 2 * size + 3;
+        let empty = ".".repeat(width);
+
+        let mut lines = vec![format!(
+            "{}S{}",
+            ".".repeat(width / 2),
+            ".".repeat(width / 2)
+        )];
+
+        for _ in 0..size {
+            lines.push(empty.clone());
+
+            let mut row = vec!['.'; width];
+
+            for x in 1..width - 1 {
+                if row[x - 1] != '^' && rng.random_bool(0.4) {
+                    row[x] = '^';
+                }
+            }
+
+            lines.push(row.into_iter().collect());
+        }
+
+        lines.push(empty);
+
+        Some(generate::lines(lines))
+    }
+}
+
+/// How many times a beam gets split, and how many timelines come out the bottom.
+fn beams(input: &[String]) -> (usize, BigUint) {
+    let width = input.iter().map(|x| x.len()).max().unwrap_or(0);
+    let start = input[0].find('S').expect("Checked when parsing.");
+
+    // timelines per column.
+    let mut timelines = vec![BigUint::ZERO; width];
+    timelines[start] = BigUint::from(1_u32);
+
+    let mut splits = 0;
+
+    for line in input.iter().skip(1) {
+        let row = line.as_bytes();
+        let mut next = vec![BigUint::ZERO; width];
+
+        for x in 0..width {
+            if timelines[x] == BigUint::ZERO {
+                continue;
+            }
+
+            if row.get(x) == Some(&b'^') {
+                splits += 1;
+                next[x - 1] += &timelines[x];
+                next[x + 1] += &timelines[x];
+            } else {
+                next[x] += &timelines[x];
+            }
+        }
+
+        timelines = next;
+    }
+
+    (splits, timelines.into_iter().sum())
+}
@@ -0,0 +1,257 @@
+use petgraph::algo;
+use petgraph::graph::UnGraph;
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day08;
+
+/// Biggest coordinate, either way, that keeps squared distances in an `isize`.
+const MAX_COORDINATE: isize = 850_000_000;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    fn distance_from(&self, other: &Self) -> isize {
+        (self.x - other.x) * (self.x - other.x)
+            + (self.y - other.y) * (self.y - other.y)
+            + (self.z - other.z) * (self.z - other.z)
+    }
+}
+
+/// Junction boxes and the pairs to connect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Playground {
+    pub points: Vec<Point3>,
+    /// How many of the closest pairs part 1 connects, the sample only asks for 10 of them.
+    pub connections: usize,
+    /// Pairs of point indices, closest first. Filled by [`Solution::prepare`].
+    pub edges: Vec<(u32, u32)>,
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let points = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let numbers = line
+                    .trim()
+                    .split(",")
+                    .map(|x| x.parse::<isize>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| ParseError::new(format!("Bad coordinate, {}.", e)).at_line(i))?;
+
+                // squared distances have to fit, 3 * (2 * 850_000_000)^2 still does.
+                if numbers
+                    .iter()
+                    .any(|x| x.unsigned_abs() > MAX_COORDINATE as usize)
+                {
+                    return Err(ParseError::new(format!(
+                        "Coordinates go up to {}.",
+                        MAX_COORDINATE
+                    ))
+                    .at_line(i));
+                }
+
+                match numbers[..] {
+                    [x, y, z] => Ok(Point3 { x, y, z }),
+                    _ => Err(ParseError::new("Expect 3 coordinates.").at_line(i)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if points.len() < 2 {
+            return Err(ParseError::new("Expect two junction boxes at least."));
+        }
+
+        Ok(Playground {
+            points,
+            connections: 1000,
+            edges: vec![],
+        })
+    }
+
+    fn for_sample(input: &mut Self::Input) {
+        input.connections = 10;
+    }
+
+    /// Both parts walk the pairs from closest to furthest, ties by index.
+    fn prepare(input: &mut Self::Input) {
+        let points = &input.points;
+        let mut edges = Vec::with_capacity(points.len() * (points.len() - 1) / 2);
+
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                let a = &points[i];
+                let b = &points[j];
+                let d = a.distance_from(b);
+                edges.push((d, i as u32, j as u32));
+            }
+        }
+
+        edges.sort_unstable();
+
+        input.edges = edges.into_iter().map(|(_, i, j)| (i, j)).collect();
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let graph = UnGraph::<u32, ()>::from_edges(input.edges.iter().take(input.connections));
+
+        let mut sccs: Vec<usize> = petgraph::algo::kosaraju_scc(&graph)
+            .iter()
+            .map(|x| x.len())
+            .collect();
+
+        sccs.sort_unstable();
+
+        Ok(Answer::from(sccs.iter().rev().take(3).product::<usize>()))
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let points = &input.points;
+        let mut graph = UnGraph::<u32, ()>::new_undirected();
+
+        for i in 0..points.len() {
+            graph.add_node(i as u32);
+        }
+
+        for e in &input.edges {
+            graph.add_edge(e.0.into(), e.1.into(), ());
+
+            if algo::connected_components(&graph) == 1 {
+                let a = points[e.0 as usize].x;
+                let b = points[e.1 as usize].x;
+                return Ok(Answer::from(b * a));
+            }
+        }
+
+        Err(SolveError::new(
+            "Ran out of pairs before everything got connected.",
+        ))
+    }
+
+    /// Every pair sorted by distance, ties by index, then a plain union-find.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        let mut circuits = Circuits::new(input.points.len());
+
+        for (i, j) in all_pairs(&input.points).into_iter().take(input.connections) {
+            circuits.join(i, j);
+        }
+
+        let mut sizes: Vec<usize> = (0..input.points.len())
+            .filter(|&x| circuits.parent[x] == x)
+            .map(|x| circuits.size[x])
+            .collect();
+
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        Some(Answer::from(sizes.iter().take(3).product::<usize>()))
+    }
+
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        let points = &input.points;
+        let mut circuits = Circuits::new(points.len());
+        let mut count = points.len();
+
+        for (i, j) in all_pairs(points) {
+            if circuits.join(i, j) {
+                count -= 1;
+            }
+
+            if count == 1 {
+                return Some(Answer::from(points[i].x * points[j].x));
+            }
+        }
+
+        None
+    }
+
+    /// `size` junction boxes on a small grid, so plenty of distances tie.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let side = size.max(2);
+        let boxes = (0..side).map(|_| {
+            format!(
+                "{},{},{}",
+                rng.random_range(0..side),
+                rng.random_range(0..side),
+                rng.random_range(0..side)
+            )
+        });
+
+        Some(generate::lines(boxes.collect::<Vec<_>>()))
+    }
+}
+
+/// Every pair of indices, closest first.
+fn all_pairs(points: &[Point3]) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            pairs.push((points[i].distance_from(&points[j]), i, j));
+        }
+    }
+
+    pairs.sort_unstable();
+    pairs.into_iter().map(|(_, i, j)| (i, j)).collect()
+}
+
+/// Union-find over the boxes.
+struct Circuits {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl Circuits {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    fn root(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+
+        x
+    }
+
+    /// `false` if they were in the same circuit already.
+    fn join(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.root(a), self.root(b));
+
+        if a == b {
+            return false;
+        }
+
+        let (big, small) = if self.size[a] < self.size[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+
+        true
+    }
+}
@@ -0,0 +1,592 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    poly,
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day09;
+
+/// Biggest coordinate, either way, that keeps the area of any rectangle in a `u128`.
+const MAX_COORDINATE: i64 = 1 << 62;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    /// Red tiles, in loop order.
+    type Input = Vec<(i64, i64)>;
+
+    /// Every corner shares a row or a column with the one before it, the last one
+    /// with the first.
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let corners: Vec<(i64, i64)> = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (x, y) = line
+                    .split_once(",")
+                    .ok_or_else(|| ParseError::new("Expect x,y.").at_line(i))?;
+
+                let number = |v: &str| {
+                    let v = v
+                        .trim()
+                        .parse::<i64>()
+                        .map_err(|e| ParseError::new(format!("NaN, {}.", e)).at_line(i))?;
+
+                    // sides of up to 2^63 + 1 tiles, squared that still fits.
+                    if v.unsigned_abs() > MAX_COORDINATE as u64 {
+                        return Err(ParseError::new(format!(
+                            "Coordinates go up to {}.",
+                            MAX_COORDINATE
+                        ))
+                        .at_line(i));
+                    }
+
+                    Ok(v)
+                };
+
+                Ok((number(x)?, number(y)?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        for (i, &a) in corners.iter().enumerate() {
+            let b = corners[(i + 1) % corners.len()];
+
+            if a.0 != b.0 && a.1 != b.1 {
+                return Err(
+                    ParseError::new("Expect the next corner in the same row or column.").at_line(i),
+                );
+            }
+        }
+
+        Ok(corners)
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let best = picked_by(input, 1);
+
+        Ok(Answer::from(best.map_or(0, |x| x.area())))
+    }
+
+    /// Tiles on the compressed grid, see [`Floor`].
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let best = picked_by(input, 2);
+
+        Ok(Answer::from(best.map_or(0, |x| x.area())))
+    }
+
+    /// Every pair of red tiles.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        let mut max_area = 0;
+
+        for (i, &(x1, y1)) in input.iter().enumerate() {
+            for &(x2, y2) in &input[i + 1..] {
+                let area = (x1.abs_diff(x2) as u128 + 1) * (y1.abs_diff(y2) as u128 + 1);
+                max_area = max_area.max(area);
+            }
+        }
+
+        Some(Answer::from(max_area))
+    }
+
+    /// Every pair of red tiles, checked with [`poly::contains_rect`].
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        let mut max_area = 0;
+
+        for (i, &a) in input.iter().enumerate() {
+            for &b in &input[i + 1..] {
+                let area = (a.0.abs_diff(b.0) as u128 + 1) * (a.1.abs_diff(b.1) as u128 + 1);
+
+                if area > max_area && poly::contains_rect(input, a, b) {
+                    max_area = area;
+                }
+            }
+        }
+
+        Some(Answer::from(max_area))
+    }
+
+    /// A loop around `size` columns of random height and depth, so about `4 * size` tiles.
+    ///
+    /// Every column crosses the middle row, which keeps the loop in one piece.
+    /// Coordinates are even, so no two edges of the loop ever touch.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let columns = size.max(1);
+        let middle = 50_000_i64;
+
+        let mut xs = vec![2 * rng.random_range(0..500_i64)];
+        let mut tops: Vec<i64> = vec![];
+        let mut bottoms: Vec<i64> = vec![];
+
+        for i in 0..columns {
+            xs.push(xs[i] + 2 * rng.random_range(1..=1000));
+
+            // neighbours never share a height, or the loop would get a useless corner.
+            let mut pick = |previous: Option<&i64>, sign: i64| loop {
+                let y = middle + 2 * sign * rng.random_range(1..=20_000);
+
+                if previous != Some(&y) {
+                    break y;
+                }
+            };
+
+            let top = pick(tops.last(), 1);
+            let bottom = pick(bottoms.last(), -1);
+            tops.push(top);
+            bottoms.push(bottom);
+        }
+
+        let mut corners = vec![(xs[0], bottoms[0]), (xs[0], tops[0])];
+
+        for i in 1..columns {
+            corners.push((xs[i], tops[i - 1]));
+            corners.push((xs[i], tops[i]));
+        }
+
+        corners.push((xs[columns], tops[columns - 1]));
+        corners.push((xs[columns], bottoms[columns - 1]));
+
+        for i in (1..columns).rev() {
+            corners.push((xs[i], bottoms[i]));
+            corners.push((xs[i], bottoms[i - 1]));
+        }
+
+        let flip = rng.random_bool(0.5);
+        let corners = corners.into_iter().map(|(x, y)| {
+            if flip {
+                format!("{},{}", y, x)
+            } else {
+                format!("{},{}", x, y)
+            }
+        });
+
+        Some(generate::lines(corners.collect::<Vec<_>>()))
+    }
+}
+
+/// Which corners of a rectangle have to be red tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corners {
+    /// Two opposite ones, like part 2.
+    Red,
+    /// At least one.
+    OneRed,
+    /// None has to, the corners can be any tile.
+    Any,
+}
+
+impl FromStr for Corners {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Corners::Red),
+            "one-red" => Ok(Corners::OneRed),
+            "any" => Ok(Corners::Any),
+            _ => Err(String::from("expected one of red, one-red, any")),
+        }
+    }
+}
+
+/// A rectangle of tiles, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub min: (i64, i64),
+    pub max: (i64, i64),
+}
+
+impl Rectangle {
+    /// Tiles in it.
+    pub fn area(&self) -> u128 {
+        (self.max.0.abs_diff(self.min.0) as u128 + 1)
+            * (self.max.1.abs_diff(self.min.1) as u128 + 1)
+    }
+}
+
+impl Display for Rectangle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{} to {},{}",
+            self.min.0, self.min.1, self.max.0, self.max.1
+        )
+    }
+}
+
+/// The loop on a compressed grid: a cell per corner coordinate, and one per gap between
+/// them. No edge ends inside a cell, so its tiles are all in or all out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Floor {
+    corners: Vec<(i64, i64)>,
+    /// First tile of every cell, and how many tiles it spans, along each axis.
+    xs: Vec<(i64, u64)>,
+    ys: Vec<(i64, u64)>,
+    /// `[x][y]`, red or green.
+    inside: Vec<Vec<bool>>,
+    /// `[x][y]`, how many cells are out below and left of the cell, for emptiness checks.
+    outside_before: Vec<Vec<u32>>,
+}
+
+/// Cells along one axis, out of every corner coordinate on it.
+fn cells(mut v: Vec<i64>) -> Vec<(i64, u64)> {
+    v.sort_unstable();
+    v.dedup();
+
+    let mut cells = vec![];
+
+    for (i, &x) in v.iter().enumerate() {
+        cells.push((x, 1));
+
+        if let Some(&next) = v.get(i + 1).filter(|&&next| next > x + 1) {
+            cells.push((x + 1, next.abs_diff(x) - 1));
+        }
+    }
+
+    cells
+}
+
+/// Position of the cell starting at `x`, which has to be a corner coordinate.
+fn cell_of(cells: &[(i64, u64)], x: i64) -> usize {
+    cells.partition_point(|c| c.0 < x)
+}
+
+impl Floor {
+    pub fn new(corners: &[(i64, i64)]) -> Self {
+        let xs = cells(corners.iter().map(|x| x.0).collect());
+        let ys = cells(corners.iter().map(|x| x.1).collect());
+
+        let mut inside = vec![vec![false; ys.len()]; xs.len()];
+        let edges = || (0..corners.len()).map(|i| (corners[i], corners[(i + 1) % corners.len()]));
+
+        // the loop itself.
+        for ((x1, y1), (x2, y2)) in edges() {
+            let (a1, a2) = (cell_of(&xs, x1.min(x2)), cell_of(&xs, x1.max(x2)));
+            let (b1, b2) = (cell_of(&ys, y1.min(y2)), cell_of(&ys, y1.max(y2)));
+
+            for column in &mut inside[a1..=a2] {
+                column[b1..=b2].fill(true);
+            }
+        }
+
+        // then a ray upwards from every cell, horizontal edges count on their left end only.
+        for (a, &(x, _)) in xs.iter().enumerate() {
+            let mut crossings: Vec<i64> = edges()
+                .filter(|((x1, y1), (x2, y2))| y1 == y2 && x1.min(x2) <= &x && &x < x1.max(x2))
+                .map(|x| x.0.1)
+                .collect();
+
+            crossings.sort_unstable();
+
+            let mut below = 0;
+
+            for (b, &(y, _)) in ys.iter().enumerate() {
+                while below < crossings.len() && crossings[below] <= y {
+                    below += 1;
+                }
+
+                inside[a][b] |= (crossings.len() - below) % 2 == 1;
+            }
+        }
+
+        let mut outside_before = vec![vec![0; ys.len() + 1]; xs.len() + 1];
+
+        for a in 0..xs.len() {
+            for b in 0..ys.len() {
+                outside_before[a + 1][b + 1] = outside_before[a][b + 1] + outside_before[a + 1][b]
+                    - outside_before[a][b]
+                    + !inside[a][b] as u32;
+            }
+        }
+
+        Self {
+            corners: corners.to_vec(),
+            xs,
+            ys,
+            inside,
+            outside_before,
+        }
+    }
+
+    /// Whether every cell from `a1, b1` to `a2, b2` is in, all included.
+    fn filled(&self, (a1, b1): (usize, usize), (a2, b2): (usize, usize)) -> bool {
+        let o = &self.outside_before;
+        o[a2 + 1][b2 + 1] + o[a1][b1] == o[a1][b2 + 1] + o[a2 + 1][b1]
+    }
+
+    /// The biggest rectangle of red and green tiles whose `corners` are red, if any fits.
+    pub fn largest(&self, corners: Corners) -> Option<Rectangle> {
+        match corners {
+            Corners::Red => self.largest_red(),
+            Corners::OneRed => self.largest_one_red(),
+            Corners::Any => self.largest_any(),
+        }
+    }
+
+    fn largest_red(&self) -> Option<Rectangle> {
+        let mut best: Option<Rectangle> = None;
+
+        for (i, &(x1, y1)) in self.corners.iter().enumerate() {
+            for &(x2, y2) in &self.corners[i + 1..] {
+                let rect = Rectangle {
+                    min: (x1.min(x2), y1.min(y2)),
+                    max: (x1.max(x2), y1.max(y2)),
+                };
+
+                if best.is_some_and(|x| x.area() >= rect.area()) {
+                    continue;
+                }
+
+                let from = (cell_of(&self.xs, rect.min.0), cell_of(&self.ys, rect.min.1));
+                let to = (cell_of(&self.xs, rect.max.0), cell_of(&self.ys, rect.max.1));
+
+                if self.filled(from, to) {
+                    best = Some(rect);
+                }
+            }
+        }
+
+        best
+    }
+
+    /// From every red tile, towards each of the four sides, widen column by column
+    /// while keeping the tallest height every column so far allows.
+    fn largest_one_red(&self) -> Option<Rectangle> {
+        let (columns, rows) = (self.xs.len(), self.ys.len());
+
+        // tiles in, from the start of the cell upwards, and from its end downwards.
+        let mut up = vec![vec![0_u64; rows + 1]; columns];
+        let mut down = vec![vec![0_u64; rows + 1]; columns];
+
+        for a in 0..columns {
+            for b in (0..rows).rev() {
+                up[a][b] = if self.inside[a][b] {
+                    self.ys[b].1 + up[a][b + 1]
+                } else {
+                    0
+                };
+            }
+
+            for b in 0..rows {
+                down[a][b + 1] = if self.inside[a][b] {
+                    self.ys[b].1 + down[a][b]
+                } else {
+                    0
+                };
+            }
+        }
+
+        let mut best: Option<Rectangle> = None;
+
+        for &(x, y) in &self.corners {
+            let (a, b) = (cell_of(&self.xs, x), cell_of(&self.ys, y));
+
+            for upwards in [true, false] {
+                let reach = |a: usize| if upwards { up[a][b] } else { down[a][b + 1] };
+
+                for right in [true, false] {
+                    let order: Vec<usize> = if right {
+                        (a..columns).collect()
+                    } else {
+                        (0..=a).rev().collect()
+                    };
+
+                    let mut height = u64::MAX;
+
+                    for c in order {
+                        height = height.min(reach(c));
+
+                        if height == 0 {
+                            break;
+                        }
+
+                        let (start, width) = self.xs[c];
+                        let far_x = if right {
+                            start + width as i64 - 1
+                        } else {
+                            start
+                        };
+                        let far_y = if upwards {
+                            y + height as i64 - 1
+                        } else {
+                            y - height as i64 + 1
+                        };
+
+                        let rect = Rectangle {
+                            min: (x.min(far_x), y.min(far_y)),
+                            max: (x.max(far_x), y.max(far_y)),
+                        };
+
+                        if best.is_none_or(|x| rect.area() > x.area()) {
+                            best = Some(rect);
+                        }
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Row by row, the cells in above form a histogram whose largest rectangle a stack finds.
+    fn largest_any(&self) -> Option<Rectangle> {
+        let columns = self.xs.len();
+        let mut heights = vec![0_u64; columns];
+        let mut best: Option<Rectangle> = None;
+
+        for (b, &(y, rows)) in self.ys.iter().enumerate() {
+            for (height, column) in heights.iter_mut().zip(&self.inside) {
+                *height = if column[b] { *height + rows } else { 0 };
+            }
+
+            let top = y + rows as i64 - 1;
+            // (first column, height), heights increasing.
+            let mut stack: Vec<(usize, u64)> = vec![];
+
+            for a in 0..=columns {
+                let height = heights.get(a).copied().unwrap_or(0);
+                let mut first = a;
+
+                while let Some(&(start, h)) = stack.last().filter(|x| x.1 >= height) {
+                    stack.pop();
+                    first = start;
+
+                    let (last_x, last_width) = self.xs[a - 1];
+
+                    let rect = Rectangle {
+                        min: (self.xs[start].0, top - h as i64 + 1),
+                        max: (last_x + last_width as i64 - 1, top),
+                    };
+
+                    if h > 0 && best.is_none_or(|x| rect.area() > x.area()) {
+                        best = Some(rect);
+                    }
+                }
+
+                stack.push((first, height));
+            }
+        }
+
+        best
+    }
+}
+
+/// The rectangle between the two red tiles furthest apart, like part 1.
+pub fn largest_pair(corners: &[(i64, i64)]) -> Option<Rectangle> {
+    let mut best: Option<Rectangle> = None;
+
+    for (i, &(x1, y1)) in corners.iter().enumerate() {
+        for &(x2, y2) in &corners[i + 1..] {
+            let rect = Rectangle {
+                min: (x1.min(x2), y1.min(y2)),
+                max: (x1.max(x2), y1.max(y2)),
+            };
+
+            if best.is_none_or(|x| rect.area() > x.area()) {
+                best = Some(rect);
+            }
+        }
+    }
+
+    best
+}
+
+/// The rectangle `part` takes its answer from, if any fits.
+pub fn picked_by(corners: &[(i64, i64)], part: u8) -> Option<Rectangle> {
+    match part {
+        1 => largest_pair(corners),
+        _ => Floor::new(corners).largest(Corners::Red),
+    }
+}
+
+/// Size of the longer side of an [`svg`] picture, in pixels.
+const SVG_SIZE: f64 = 1000.0;
+const SVG_MARGIN: f64 = 20.0;
+
+/// The loop as an SVG picture, scaled to fit [`SVG_SIZE`]: red and green tiles filled in,
+/// red tiles marked, the part 1 rectangle in blue and the part 2 one in orange.
+///
+/// Everything goes through tile centres, so rectangles end right on their red corners.
+pub fn svg(corners: &[(i64, i64)]) -> String {
+    let min_x = corners.iter().map(|x| x.0).min().unwrap_or(0);
+    let min_y = corners.iter().map(|x| x.1).min().unwrap_or(0);
+    let max_x = corners.iter().map(|x| x.0).max().unwrap_or(0);
+    let max_y = corners.iter().map(|x| x.1).max().unwrap_or(0);
+
+    let (tiles_x, tiles_y) = (
+        max_x.abs_diff(min_x) as f64 + 1.0,
+        max_y.abs_diff(min_y) as f64 + 1.0,
+    );
+    let scale = SVG_SIZE / tiles_x.max(tiles_y);
+
+    let px = |x: i64, min: i64| x.abs_diff(min) as f64 * scale + scale / 2.0 + SVG_MARGIN;
+    let (width, height) = (
+        tiles_x * scale + 2.0 * SVG_MARGIN,
+        tiles_y * scale + 2.0 * SVG_MARGIN,
+    );
+
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" viewBox=\"0 0 {:.2} {:.2}\">\n",
+        width, height, width, height
+    );
+
+    out += "  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n";
+
+    let points: Vec<String> = corners
+        .iter()
+        .map(|&(x, y)| format!("{:.2},{:.2}", px(x, min_x), px(y, min_y)))
+        .collect();
+
+    out += &format!(
+        "  <polygon points=\"{}\" fill=\"#a5d6a7\" stroke=\"#2e7d32\" stroke-width=\"1\"/>\n",
+        points.join(" ")
+    );
+
+    for (part, colour) in [(1, "#1e88e5"), (2, "#fb8c00")] {
+        let Some(rect) = picked_by(corners, part) else {
+            continue;
+        };
+
+        let (x, y) = (px(rect.min.0, min_x), px(rect.min.1, min_y));
+
+        out += &format!(
+            "  <rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\" stroke-dasharray=\"8 4\"><title>Part {}: {}, {} tiles</title></rect>\n",
+            x,
+            y,
+            px(rect.max.0, min_x) - x,
+            px(rect.max.1, min_y) - y,
+            colour,
+            part,
+            rect,
+            rect.area()
+        );
+    }
+
+    // big enough to see on a small loop, small enough not to cover a big one.
+    let radius = (scale * 0.3).clamp(1.5, 6.0);
+
+    out += "  <g fill=\"#e53935\">\n";
+
+    for &(x, y) in corners {
+        out += &format!(
+            "    <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\"><title>{},{}</title></circle>\n",
+            px(x, min_x),
+            px(y, min_y),
+            radius,
+            x,
+            y
+        );
+    }
+
+    out += "  </g>\n</svg>\n";
+    out
+}
@@ -0,0 +1,573 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Display, Formatter},
+};
+
+use rand::RngExt;
+
+use crate::libs::{
+    answer::Answer,
+    generate::{self, Rng},
+    ilp::{Backend, Problem},
+    search::{self, Budget, OverBudget, State},
+    solution::{ParseError, Solution, SolveError},
+};
+
+pub struct Day10;
+
+/// One line of the manual.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    /// Which lights should end up on.
+    pub target: Vec<bool>,
+    /// Lights, or counters, each button touches.
+    pub toggles: Vec<Vec<u32>>,
+    pub jolts: Vec<u32>,
+}
+
+impl Machine {
+    /// Part 2 as an integer program, the buttons bump counters instead of toggling lights.
+    pub fn jolt_problem(&self) -> Problem<'_> {
+        Problem {
+            buttons: &self.toggles,
+            target: &self.jolts,
+        }
+    }
+}
+
+/// The pieces of a line of the manual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Open(char),
+    Close(char),
+    Comma,
+    /// `#` is on, `.` is off.
+    Light(bool),
+    Number(u32),
+    Space,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Open(x) | Token::Close(x) => write!(f, "{:?}", x),
+            Token::Comma => write!(f, "','"),
+            Token::Light(true) => write!(f, "'#'"),
+            Token::Light(false) => write!(f, "'.'"),
+            Token::Number(x) => write!(f, "{}", x),
+            Token::Space => write!(f, "a space"),
+        }
+    }
+}
+
+/// Tokens of line `i`, each with the column it starts at.
+fn tokenize(line: &str, i: usize) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens: Vec<(usize, Token)> = vec![];
+    let mut chars = line.chars().enumerate().peekable();
+
+    while let Some((j, x)) = chars.next() {
+        let token = match x {
+            '[' | '(' | '{' => Token::Open(x),
+            ']' | ')' | '}' => Token::Close(x),
+            ',' => Token::Comma,
+            '#' => Token::Light(true),
+            '.' => Token::Light(false),
+            ' ' => {
+                while chars.next_if(|x| x.1 == ' ').is_some() {}
+                Token::Space
+            }
+            '0'..='9' => {
+                let mut number = x.to_digit(10).expect("A digit.");
+
+                while let Some((_, d)) = chars.next_if(|x| x.1.is_ascii_digit()) {
+                    number = number
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(d.to_digit(10).expect("A digit.")))
+                        .ok_or_else(|| ParseError::new("Number too large.").at(i, j))?;
+                }
+
+                Token::Number(number)
+            }
+            _ => return Err(ParseError::new(format!("Unexpected {:?}.", x)).at(i, j)),
+        };
+
+        tokens.push((j, token));
+    }
+
+    Ok(tokens)
+}
+
+/// `(a,b,...)` or `{a,b,...}` starting at `tokens[k]`, returns the numbers and where it ends.
+fn list(
+    tokens: &[(usize, Token)],
+    mut k: usize,
+    (open, close): (char, char),
+    end: usize,
+    i: usize,
+) -> Result<(Vec<u32>, usize), ParseError> {
+    let expected = |k: usize, what: &str| {
+        let error = match tokens.get(k) {
+            Some((j, found)) => {
+                ParseError::new(format!("Expect {}, found {}.", what, found)).at(i, *j)
+            }
+            None => {
+                ParseError::new(format!("Expect {}, found the end of the line.", what)).at(i, end)
+            }
+        };
+
+        Err(error)
+    };
+
+    if tokens.get(k).map(|x| x.1) != Some(Token::Open(open)) {
+        return expected(k, &format!("{:?}", open));
+    }
+
+    let mut numbers = vec![];
+
+    loop {
+        k += 1;
+
+        let Some(&(_, Token::Number(x))) = tokens.get(k) else {
+            return expected(k, "a number");
+        };
+
+        numbers.push(x);
+        k += 1;
+
+        match tokens.get(k).map(|x| x.1) {
+            Some(Token::Comma) => continue,
+            Some(Token::Close(x)) if x == close => return Ok((numbers, k + 1)),
+            _ => return expected(k, &format!("',' or {:?}", close)),
+        }
+    }
+}
+
+/// Most buttons a machine may have, [`Day10::reference_1`] tries every set of them as bits.
+const MAX_BUTTONS: usize = 64;
+
+/// `[.##.] (3) (1,3) ... {3,5,4,7}`: the lights, buttons with the lights they toggle,
+/// then the joltages. Groups are apart by spaces, buttons only wire lights that exist,
+/// and there is one joltage per light.
+fn machine(tokens: &[(usize, Token)], end: usize, i: usize) -> Result<Machine, ParseError> {
+    let at = |k: usize| tokens.get(k).map_or(end, |x| x.0);
+
+    if tokens.first().map(|x| x.1) != Some(Token::Open('[')) {
+        return Err(ParseError::new("Expect lights like [.##.].").at(i, 0));
+    }
+
+    let mut k = 1;
+    let mut target = vec![];
+
+    while let Some(&(_, Token::Light(x))) = tokens.get(k) {
+        target.push(x);
+        k += 1;
+    }
+
+    if tokens.get(k).map(|x| x.1) != Some(Token::Close(']')) {
+        return Err(ParseError::new("Expect '.', '#' or ']'.").at(i, at(k)));
+    }
+
+    k += 1;
+
+    let mut toggles: Vec<Vec<u32>> = vec![];
+
+    loop {
+        if tokens.get(k).map(|x| x.1) != Some(Token::Space) {
+            return Err(ParseError::new("Expect a space.").at(i, at(k)));
+        }
+
+        k += 1;
+
+        if tokens.get(k).map(|x| x.1) != Some(Token::Open('(')) {
+            break;
+        }
+
+        let start = at(k);
+
+        if toggles.len() == MAX_BUTTONS {
+            return Err(
+                ParseError::new(format!("Expect at most {} buttons.", MAX_BUTTONS)).at(i, start),
+            );
+        }
+
+        let (button, next) = list(tokens, k, ('(', ')'), end, i)?;
+
+        if let Some(x) = button.iter().find(|&&x| x as usize >= target.len()) {
+            return Err(ParseError::new(format!(
+                "Light {} is not there, there are {}.",
+                x,
+                target.len()
+            ))
+            .at(i, start));
+        }
+
+        if (1..button.len()).any(|j| button[..j].contains(&button[j])) {
+            return Err(ParseError::new("A button toggles a light twice.").at(i, start));
+        }
+
+        toggles.push(button);
+        k = next;
+    }
+
+    let start = at(k);
+    let (jolts, k) = list(tokens, k, ('{', '}'), end, i)?;
+
+    if jolts.len() != target.len() {
+        return Err(ParseError::new(format!(
+            "Expect {} joltages, one per light, found {}.",
+            target.len(),
+            jolts.len()
+        ))
+        .at(i, start));
+    }
+
+    if k < tokens.len() {
+        return Err(ParseError::new("Expect the end of the line.").at(i, at(k)));
+    }
+
+    Ok(Machine {
+        target,
+        toggles,
+        jolts,
+    })
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    /// One machine per line, see [`machine`] for the grammar.
+    #[forbid(unsafe_code)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| machine(&tokenize(line, i)?, line.chars().count(), i))
+            .collect()
+    }
+
+    #[forbid(unsafe_code)]
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let mut total = 0;
+
+        for (i, machine) in input.iter().enumerate() {
+            let presses = fewest_toggles(machine)
+                .map_err(|e| SolveError::new(format!("Machine {}: {}", i + 1, e)))?
+                .ok_or_else(|| {
+                    SolveError::new(format!("Machine {}: no way to reach the lights.", i + 1))
+                })?;
+
+            total += presses.iter().sum::<u32>();
+        }
+
+        Ok(Answer::from(total))
+    }
+
+    /// With the fastest backend built in, see [`part_2_with`] for the others.
+    #[forbid(unsafe_code)]
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError> {
+        part_2_with(input, Backend::default())
+    }
+
+    /// Try every set of buttons, pressing one twice does nothing.
+    fn reference_1(input: &Self::Input) -> Option<Answer> {
+        let mut total = 0;
+
+        for machine in input {
+            let buttons = machine.toggles.len();
+
+            let fewest = (0..1_u128 << buttons)
+                .filter(|&set| {
+                    let mut lights = vec![false; machine.target.len()];
+
+                    for (b, toggle) in machine.toggles.iter().enumerate() {
+                        if set >> b & 1 == 1 {
+                            for &x in toggle {
+                                lights[x as usize] = !lights[x as usize];
+                            }
+                        }
+                    }
+
+                    lights == machine.target
+                })
+                .map(|set| set.count_ones())
+                .min()?;
+
+            total += fewest;
+        }
+
+        Some(Answer::from(total))
+    }
+
+    /// An exhaustive search with integers only, see [`Backend::Exact`].
+    fn reference_2(input: &Self::Input) -> Option<Answer> {
+        let mut total = 0;
+
+        for machine in input {
+            let presses = Backend::Exact.solve(machine.jolt_problem())?;
+            total += presses.iter().map(|&x| x as u64).sum::<u64>();
+        }
+
+        Some(Answer::from(total))
+    }
+
+    /// `size` machines with up to `size` lights, capped at 10, always solvable.
+    ///
+    /// Buttons get pressed up to `size` times too, small sizes stay in reach of the reference.
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let machines = (0..size.max(1)).map(|_| {
+            let lights = rng.random_range(1..=size.clamp(1, 10));
+            let button_count = rng.random_range(1..=lights + 2);
+
+            let buttons: Vec<Vec<usize>> = (0..button_count)
+                .map(|_| {
+                    let mut button: Vec<usize> =
+                        (0..lights).filter(|_| rng.random_bool(0.4)).collect();
+
+                    if button.is_empty() {
+                        button.push(rng.random_range(0..lights));
+                    }
+
+                    button
+                })
+                .collect();
+
+            // press buttons at random, whatever comes out is reachable.
+            let mut target = vec![false; lights];
+            let mut jolts = vec![0_u32; lights];
+
+            for button in &buttons {
+                let presses = rng.random_range(0..=size.min(10) as u32);
+
+                for &light in button {
+                    if presses % 2 == 1 {
+                        target[light] = !target[light];
+                    }
+
+                    jolts[light] += presses;
+                }
+            }
+
+            let target: String = target.iter().map(|&x| if x { '#' } else { '.' }).collect();
+            let buttons: Vec<String> = buttons
+                .iter()
+                .map(|x| {
+                    let lights: Vec<String> = x.iter().map(|x| x.to_string()).collect();
+                    format!("({})", lights.join(","))
+                })
+                .collect();
+            let jolts: Vec<String> = jolts.iter().map(|x| x.to_string()).collect();
+
+            format!("[{}] {} {{{}}}", target, buttons.join(" "), jolts.join(","))
+        });
+
+        Some(generate::lines(machines.collect::<Vec<_>>()))
+    }
+}
+
+/// How much memory part 1 may spend on configurations it has seen.
+const SEARCH_BUDGET: Budget = Budget::from_bytes(1 << 30);
+
+/// Presses per button that turn the lights from all off to the target, fewest in total.
+/// `None` if the target is out of reach, an error if finding out takes more than [`SEARCH_BUDGET`].
+///
+/// Searches from both ends with the lights packed in a `u128`, when there are few enough.
+pub fn fewest_toggles(machine: &Machine) -> Result<Option<Vec<u32>>, OverBudget> {
+    if machine.target.len() > State::BITS as usize {
+        return fewest_toggles_wide(machine);
+    }
+
+    let pack = |lights: &mut dyn Iterator<Item = usize>| lights.fold(0, |s: State, x| s | 1 << x);
+
+    let toggles: Vec<State> = machine
+        .toggles
+        .iter()
+        .map(|x| pack(&mut x.iter().map(|&x| x as usize)))
+        .collect();
+    let goal = pack(&mut (0..machine.target.len()).filter(|&x| machine.target[x]));
+
+    // toggling twice undoes it, so the moves are the same both ways.
+    let moves = |s: State| {
+        toggles
+            .iter()
+            .map(|t| s ^ t)
+            .enumerate()
+            .collect::<Vec<_>>()
+    };
+
+    let Some(path) = search::bidirectional(0, goal, moves, moves, SEARCH_BUDGET)? else {
+        return Ok(None);
+    };
+
+    let mut presses = vec![0; toggles.len()];
+
+    for button in path {
+        presses[button] += 1;
+    }
+
+    Ok(Some(presses))
+}
+
+/// [`fewest_toggles`] for machines with more lights than a `u128` holds.
+fn fewest_toggles_wide(machine: &Machine) -> Result<Option<Vec<u32>>, OverBudget> {
+    let target = &machine.target;
+
+    // wider configurations cost more, so fewer of them fit in the same bytes.
+    let budget = SEARCH_BUDGET.max_states * State::BITS as usize / target.len();
+    let start: Vec<bool> = vec![false; target.len()];
+
+    // how each configuration was first reached, (previous configuration, button).
+    let mut seen_state = HashMap::new();
+    seen_state.insert(start.clone(), None);
+
+    let mut deq = VecDeque::new();
+    deq.push_back(start);
+
+    while let Some(config) = deq.pop_front() {
+        if config == *target {
+            let mut presses = vec![0; machine.toggles.len()];
+            let mut current = &config;
+
+            while let Some((previous, button)) = &seen_state[current] {
+                presses[*button] += 1;
+                current = previous;
+            }
+
+            return Ok(Some(presses));
+        }
+
+        if seen_state.len() > budget {
+            return Err(OverBudget {
+                visited: seen_state.len(),
+            });
+        }
+
+        for (button, toggle) in machine.toggles.iter().enumerate() {
+            let mut new_config = config.clone();
+
+            for selected in toggle {
+                let index = *selected as usize;
+                new_config[index] = !new_config[index];
+            }
+
+            if !seen_state.contains_key(&new_config) {
+                seen_state.insert(new_config.clone(), Some((config.clone(), button)));
+                deq.push_back(new_config);
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// [`Day10::part_2`] solved with `backend`, i.e. for `run --solver`.
+pub fn part_2_with(machines: &[Machine], backend: Backend) -> Result<Answer, SolveError> {
+    let mut total = 0;
+
+    for (i, machine) in machines.iter().enumerate() {
+        let presses = fewest_jolt_presses(machine, backend).ok_or_else(|| {
+            SolveError::new(format!("Machine {}: no way to reach the joltages.", i + 1))
+        })?;
+
+        total += presses.iter().map(|&x| x as u64).sum::<u64>();
+    }
+
+    Ok(Answer::from(total))
+}
+
+/// Presses per button that bring every counter to its joltage, fewest in total.
+/// `None` if no such presses exist.
+pub fn fewest_jolt_presses(machine: &Machine, backend: Backend) -> Option<Vec<u32>> {
+    backend.solve(machine.jolt_problem())
+}
+
+/// Lights and counters after pressing each button as often as `presses` says, from all off.
+pub fn simulate(machine: &Machine, presses: &[u32]) -> (Vec<bool>, Vec<u32>) {
+    let mut lights = vec![false; machine.target.len()];
+    let mut counters = vec![0; machine.jolts.len()];
+
+    for (toggle, &count) in machine.toggles.iter().zip(presses) {
+        for &x in toggle {
+            lights[x as usize] ^= count % 2 == 1;
+            counters[x as usize] += count;
+        }
+    }
+
+    (lights, counters)
+}
+
+/// Whether `presses` reach what `part` asks for: the lights for part 1, the joltages for part 2.
+pub fn verify(machine: &Machine, presses: &[u32], part: u8) -> bool {
+    if presses.len() != machine.toggles.len() {
+        return false;
+    }
+
+    let (lights, counters) = simulate(machine, presses);
+
+    match part {
+        1 => lights == machine.target,
+        _ => counters == machine.jolts,
+    }
+}
+
+/// Button by button, what `presses` do to the machine, for humans.
+pub fn explain(machine: &Machine, presses: &[u32], part: u8) -> String {
+    let mut text = format!("{}\n", machine);
+    let mut so_far = vec![0; presses.len()];
+
+    for (button, &count) in presses.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+
+        so_far[button] = count;
+
+        let (lights, counters) = simulate(machine, &so_far);
+        let state = if part == 1 {
+            lights_to_string(&lights)
+        } else {
+            format!("{{{}}}", join(&counters))
+        };
+
+        text.push_str(&format!(
+            "  press ({}) {} time(s) -> {}\n",
+            join(&machine.toggles[button]),
+            count,
+            state
+        ));
+    }
+
+    let total: u32 = presses.iter().sum();
+
+    if verify(machine, presses, part) {
+        text.push_str(&format!("  {} press(es), target reached.\n", total));
+    } else {
+        text.push_str(&format!("  {} press(es), target NOT reached.\n", total));
+    }
+
+    text
+}
+
+fn join(numbers: &[u32]) -> String {
+    let numbers: Vec<String> = numbers.iter().map(|x| x.to_string()).collect();
+    numbers.join(",")
+}
+
+fn lights_to_string(lights: &[bool]) -> String {
+    let lights: String = lights.iter().map(|&x| if x { '#' } else { '.' }).collect();
+    format!("[{}]", lights)
+}
+
+/// Back to the manual's format.
+impl Display for Machine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", lights_to_string(&self.target))?;
+
+        for toggle in &self.toggles {
+            write!(f, " ({})", join(toggle))?;
+        }
+
+        write!(f, " {{{}}}", join(&self.jolts))
+    }
+}
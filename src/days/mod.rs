@@ -0,0 +1,49 @@
+use crate::libs::solution::{Day, Erased};
+
+pub mod day_00;
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+
+/// Every day with a registered solver, `day_00` is only the template.
+///
+/// Remember to add new days here after scaffolding them.
+pub static DAYS: [&dyn Day; 10] = [
+    &Erased::<day_01::Day01>::new(),
+    &Erased::<day_02::Day02>::new(),
+    &Erased::<day_03::Day03>::new(),
+    &Erased::<day_04::Day04>::new(),
+    &Erased::<day_05::Day05>::new(),
+    &Erased::<day_06::Day06>::new(),
+    &Erased::<day_07::Day07>::new(),
+    &Erased::<day_08::Day08>::new(),
+    &Erased::<day_09::Day09>::new(),
+    &Erased::<day_10::Day10>::new(),
+];
+
+/// Look up a registered day.
+///
+/// ```
+/// use aoc_2025::{days, libs::answer::Answer};
+///
+/// let day = days::get(5).unwrap();
+///
+/// assert_eq!(day.solve("3-5\n10-14\n\n1\n", false, 2), Ok(Answer::from(8)));
+/// assert!(day.solve("3-5\nhuh\n", false, 2).is_err());
+/// assert!(days::get(0).is_none());
+/// ```
+pub fn get(day: u8) -> Option<&'static dyn Day> {
+    DAYS.iter().find(|x| x.day() == day).copied()
+}
+
+/// Numbers of every registered day, in order.
+pub fn numbers() -> Vec<u8> {
+    DAYS.iter().map(|x| x.day()).collect()
+}
@@ -1 +1,4 @@
-pub mod libs;
\ No newline at end of file
+#![forbid(unsafe_code)]
+
+pub mod days;
+pub mod libs;
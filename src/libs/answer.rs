@@ -0,0 +1,214 @@
+use std::fmt::{self, Display, Formatter};
+
+use num_bigint::{BigInt, BigUint, Sign};
+use serde::{Deserialize, Serialize};
+
+/// Whatever a part returns, in one type.
+///
+/// Integers are stored in the smallest variant that fits, so equality is exact
+/// no matter which type the day computed with:
+///
+/// ```
+/// use num_bigint::BigUint;
+/// use aoc_2025::libs::answer::Answer;
+///
+/// assert_eq!(Answer::from(24_usize), Answer::from(24_i128));
+/// assert_eq!(Answer::from(24_f64), Answer::from(BigUint::from(24_u32)));
+/// assert_ne!(Answer::from(-1_i64), Answer::from(1_u8));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Answer {
+    Unsigned(u128),
+    /// Only ever negative, non-negative values are [`Answer::Unsigned`].
+    Signed(i128),
+    /// Anything outside of `i128..=u128`.
+    Big(BigInt),
+    Text(String),
+    /// Letters drawn on a grid, one row per line.
+    Grid(String),
+}
+
+impl Answer {
+    /// Join rows into a [`Answer::Grid`].
+    pub fn grid<I, S>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let rows: Vec<String> = rows.into_iter().map(|x| x.as_ref().to_owned()).collect();
+        Answer::Grid(rows.join("\n"))
+    }
+
+    /// Short name of the variant, for machine readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Big(_) => "big",
+            Answer::Text(_) => "text",
+            Answer::Grid(_) => "grid",
+        }
+    }
+
+    /// The answer seems a little bit cute today?
+    ///
+    /// ```
+    /// use aoc_2025::libs::answer::Answer;
+    ///
+    /// assert_eq!(
+    ///     Answer::from(7_u32).decorated().to_string(),
+    ///     "Feeling like 7 because I'm cute :3"
+    /// );
+    /// ```
+    pub fn decorated(&self) -> Decorated<'_> {
+        Decorated(self)
+    }
+}
+
+/// Plain and machine readable, this is what gets submitted.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(x) => write!(f, "{}", x),
+            Answer::Signed(x) => write!(f, "{}", x),
+            Answer::Big(x) => write!(f, "{}", x),
+            Answer::Text(x) | Answer::Grid(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+/// See [`Answer::decorated`].
+pub struct Decorated<'a>(&'a Answer);
+
+impl Display for Decorated<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Answer::Grid(x) => write!(f, "Feeling like this because I'm cute :3\n{}", x),
+            x => write!(f, "Feeling like {} because I'm cute :3", x),
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Unsigned(value as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    if value >= 0 {
+                        Answer::Unsigned(value as u128)
+                    } else {
+                        Answer::Signed(value as i128)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        if let Ok(x) = u128::try_from(&value) {
+            Answer::Unsigned(x)
+        } else if let Ok(x) = i128::try_from(&value) {
+            Answer::Signed(x)
+        } else {
+            Answer::Big(value)
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        Answer::from(BigInt::from_biguint(Sign::Plus, value))
+    }
+}
+
+/// Whole numbers become integers, anything else is kept as text.
+impl From<f64> for Answer {
+    fn from(value: f64) -> Self {
+        if value.is_finite() && value.fract() == 0.0 && value.abs() < 2f64.powi(127) {
+            Answer::from(value as i128)
+        } else {
+            Answer::Text(value.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn picks_the_smallest_variant() {
+        assert_eq!(Answer::from(5_i32), Answer::Unsigned(5));
+        assert_eq!(Answer::from(-5_i32), Answer::Signed(-5));
+        assert_eq!(Answer::from(BigInt::from(-5)), Answer::Signed(-5));
+
+        let huge = BigUint::from(u128::MAX) * 10_u32;
+        assert_eq!(Answer::from(huge.clone()).kind(), "big");
+        assert_eq!(
+            Answer::from(huge).to_string(),
+            "3402823669209384634633746074317682114550"
+        );
+    }
+
+    #[test]
+    fn keeps_fractions_as_text() {
+        assert_eq!(Answer::from(2.5_f64), Answer::Text(String::from("2.5")));
+        assert_eq!(Answer::from(-3_f64), Answer::Signed(-3));
+    }
+
+    #[test]
+    fn prints_grids_line_by_line() {
+        let grid = Answer::grid(["#..", ".#."]);
+
+        assert_eq!(grid.to_string(), "#..\n.#.");
+        assert_eq!(
+            grid.decorated().to_string(),
+            "Feeling like this because I'm cute :3\n#..\n.#."
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn same_number_same_answer(x in any::<i128>()) {
+            let answer = Answer::from(x);
+
+            prop_assert_eq!(&answer, &Answer::from(BigInt::from(x)));
+            prop_assert_eq!(answer.to_string(), x.to_string());
+        }
+
+        #[test]
+        fn whole_floats_are_integers(x in -(1_i64 << 52)..(1_i64 << 52)) {
+            prop_assert_eq!(Answer::from(x as f64), Answer::from(x));
+        }
+    }
+}
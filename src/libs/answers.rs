@@ -0,0 +1,277 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+};
+
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+/// Default location of the answers database.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without any hint.
+    Wrong,
+    /// Submitted too soon after the previous attempt, nothing was checked.
+    Wait,
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::WrongLevel => "wrong level",
+        };
+
+        write!(f, "{}", text)
+    }
+}
+
+/// One submission, kept for the record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp, in seconds.
+    pub at: u64,
+}
+
+/// Everything we know about the answer of one part of one input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// The confirmed answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+
+    /// Smallest answer known to be too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<String>,
+
+    /// Largest answer known to be too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<Attempt>,
+}
+
+/// Why an answer should not be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong,
+    /// At least as high as something already too high.
+    NotBelow(String),
+    /// At most as low as something already too low.
+    NotAbove(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved, the answer is {}", answer),
+            Refusal::KnownWrong => write!(f, "this answer was already rejected"),
+            Refusal::NotBelow(bound) => write!(f, "{} is already too high", bound),
+            Refusal::NotAbove(bound) => write!(f, "{} is already too low", bound),
+        }
+    }
+}
+
+impl Record {
+    /// Check an answer against everything already learned, without asking the server.
+    pub fn check(&self, answer: &str) -> Result<(), Refusal> {
+        if let Some(correct) = &self.answer {
+            return if correct == answer {
+                Err(Refusal::AlreadySolved(correct.clone()))
+            } else {
+                Err(Refusal::KnownWrong)
+            };
+        }
+
+        if self.wrong.iter().any(|x| x == answer) {
+            return Err(Refusal::KnownWrong);
+        }
+
+        // bounds only make sense for numbers.
+        if let Ok(value) = answer.parse::<BigInt>() {
+            if let Some(bound) = &self.too_high
+                && bound.parse::<BigInt>().is_ok_and(|b| value >= b)
+            {
+                return Err(Refusal::NotBelow(bound.clone()));
+            }
+
+            if let Some(bound) = &self.too_low
+                && bound.parse::<BigInt>().is_ok_and(|b| value <= b)
+            {
+                return Err(Refusal::NotAbove(bound.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remember what the server said, tightening the bounds if possible.
+    pub fn learn(&mut self, answer: &str, verdict: Verdict, at: u64) {
+        self.attempts.push(Attempt {
+            answer: answer.to_owned(),
+            verdict,
+            at,
+        });
+
+        let tighter = |bound: &Option<String>, higher: bool| match bound {
+            None => true,
+            Some(bound) => match (answer.parse::<BigInt>(), bound.parse::<BigInt>()) {
+                (Ok(a), Ok(b)) => (a > b) == higher,
+                _ => false,
+            },
+        };
+
+        match verdict {
+            Verdict::Correct => self.answer = Some(answer.to_owned()),
+            Verdict::TooHigh => {
+                if tighter(&self.too_high, false) {
+                    self.too_high = Some(answer.to_owned());
+                }
+            }
+            Verdict::TooLow => {
+                if tighter(&self.too_low, true) {
+                    self.too_low = Some(answer.to_owned());
+                }
+            }
+            Verdict::Wrong => {}
+            Verdict::Wait | Verdict::WrongLevel => return,
+        }
+
+        if verdict != Verdict::Correct && !self.wrong.iter().any(|x| x == answer) {
+            self.wrong.push(answer.to_owned());
+        }
+    }
+}
+
+/// Known answers and submission history, keyed by day, input name and part.
+///
+/// ```toml
+/// [day_05.personal.part_1]
+/// answer = "3"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, BTreeMap<String, Record>>>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{:0>2}", day)
+}
+
+fn part_key(part: u8) -> String {
+    format!("part_{}", part)
+}
+
+impl Answers {
+    /// Load the database, a missing file is an empty database.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        fs::write(path, content)
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&Record> {
+        self.days
+            .get(&day_key(day))?
+            .get(input)?
+            .get(&part_key(part))
+    }
+
+    pub fn entry(&mut self, day: u8, input: &str, part: u8) -> &mut Record {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_owned())
+            .or_default()
+            .entry(part_key(part))
+            .or_default()
+    }
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+
+    #[test]
+    fn refuses_known_values() {
+        let mut record = Record::default();
+
+        record.learn("100", Verdict::TooHigh, 0);
+        record.learn("20", Verdict::TooLow, 0);
+        record.learn("50", Verdict::Wrong, 0);
+
+        assert_eq!(record.check("50"), Err(Refusal::KnownWrong));
+        assert_eq!(record.check("150"), Err(Refusal::NotBelow("100".into())));
+        assert_eq!(record.check("15"), Err(Refusal::NotAbove("20".into())));
+        assert_eq!(record.check("42"), Ok(()));
+
+        record.learn("42", Verdict::Correct, 0);
+
+        assert_eq!(record.check("42"), Err(Refusal::AlreadySolved("42".into())));
+        assert_eq!(record.check("43"), Err(Refusal::KnownWrong));
+    }
+
+    #[test]
+    fn keeps_the_tightest_bound() {
+        let mut record = Record::default();
+
+        record.learn("100", Verdict::TooHigh, 0);
+        record.learn("120", Verdict::TooHigh, 0);
+        record.learn("80", Verdict::TooHigh, 0);
+
+        assert_eq!(record.too_high.as_deref(), Some("80"));
+        assert_eq!(record.attempts.len(), 3);
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers
+            .entry(5, "personal", 1)
+            .learn("3", Verdict::Correct, 7);
+
+        let text = toml::to_string_pretty(&answers).unwrap();
+        assert!(text.contains("[day_05.personal.part_1]"));
+
+        let parsed: Answers = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(
+            parsed.get(5, "personal", 1).unwrap().answer.as_deref(),
+            Some("3")
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    hint,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Summary of repeated timings, everything in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub min_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    /// Summarise a non-empty list of timings.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc_2025::libs::bench::Stats;
+    ///
+    /// let samples = [3, 1, 2].map(Duration::from_nanos);
+    /// let stats = Stats::from_samples(&samples);
+    ///
+    /// assert_eq!(stats.median_ns, 2.0);
+    /// assert_eq!(stats.min_ns, 1.0);
+    /// assert_eq!(stats.stddev_ns, 1.0);
+    /// ```
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut ns: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        ns.sort_by(f64::total_cmp);
+
+        let n = ns.len();
+        let mean = ns.iter().sum::<f64>() / n as f64;
+
+        let median = if n % 2 == 1 {
+            ns[n / 2]
+        } else {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2.0
+        };
+
+        // sample standard deviation, a single run has none.
+        let stddev = if n > 1 {
+            (ns.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        Self {
+            runs: n,
+            mean_ns: mean,
+            median_ns: median,
+            min_ns: ns[0],
+            stddev_ns: stddev,
+        }
+    }
+}
+
+/// Run `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure<T, F: FnMut() -> T>(warmup: usize, iterations: usize, mut f: F) -> Stats {
+    for _ in 0..warmup {
+        hint::black_box(f());
+    }
+
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            hint::black_box(f());
+            now.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+/// Pick a unit that keeps a few significant digits.
+///
+/// ```
+/// use aoc_2025::libs::bench::format_ns;
+///
+/// assert_eq!(format_ns(512.0), "512 ns");
+/// assert_eq!(format_ns(1_500.0), "1.50 µs");
+/// assert_eq!(format_ns(23_400_000.0), "23.40 ms");
+/// ```
+pub fn format_ns(ns: f64) -> String {
+    if ns < 1e3 {
+        format!("{:.0} ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.2} µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2} ms", ns / 1e6)
+    } else {
+        format!("{:.2} s", ns / 1e9)
+    }
+}
+
+/// Timings of one part of one day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// Only known for days that parse separately from solving.
+    pub parse: Option<Stats>,
+    pub solve: Stats,
+}
+
+/// Render measurements as a Markdown table, ready for the README.
+pub fn to_markdown(measurements: &[Measurement]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Parse (median) | Mean | Median | Min | Std dev | Runs |\n\
+         | --: | ---: | -------------: | ---: | -----: | --: | ------: | ---: |\n",
+    );
+
+    for m in measurements {
+        let parse = match &m.parse {
+            Some(parse) => format_ns(parse.median_ns),
+            None => String::from("-"),
+        };
+
+        table.push_str(&format!(
+            "| {:0>2} | {} | {} | {} | {} | {} | {} | {} |\n",
+            m.day,
+            m.part,
+            parse,
+            format_ns(m.solve.mean_ns),
+            format_ns(m.solve.median_ns),
+            format_ns(m.solve.min_ns),
+            format_ns(m.solve.stddev_ns),
+            m.solve.runs
+        ));
+    }
+
+    table
+}
@@ -0,0 +1,238 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::libs::bench::{Measurement, format_ns};
+
+/// Default location of the history, one JSON object per line.
+pub const HISTORY_FILE: &str = "bench_history.jsonl";
+
+/// One measurement of one bench run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// When the run started, in Unix milliseconds. Shared by every entry of a run.
+    pub run: u64,
+    pub commit: String,
+    pub machine: String,
+    /// Name given to the run so it can be compared against later.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+    pub measurement: Measurement,
+}
+
+/// Short hash of `HEAD`, with a `-dirty` suffix for uncommitted changes.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|x| x.status.success())
+            .map(|x| String::from_utf8_lossy(&x.stdout).trim().to_owned())
+    };
+
+    let Some(hash) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return String::from("unknown");
+    };
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => hash + "-dirty",
+        _ => hash,
+    }
+}
+
+/// The host name, timings from different machines should not be compared.
+pub fn machine_name() -> String {
+    fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .map(|x| x.trim().to_owned())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for entry in entries {
+        let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+        writeln!(file, "{}", line)?;
+    }
+
+    Ok(())
+}
+
+/// Load the history, a missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    content
+        .lines()
+        .filter(|x| !x.trim().is_empty())
+        .map(|x| serde_json::from_str(x).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+        .collect()
+}
+
+/// Every entry of one run.
+pub fn run_of(history: &[Entry], run: u64) -> Vec<&Entry> {
+    history.iter().filter(|x| x.run == run).collect()
+}
+
+/// The latest run on a machine, and the one it should be compared against:
+/// the latest run named `baseline` if given, the one before it otherwise.
+pub fn pick_runs(history: &[Entry], machine: &str, baseline: Option<&str>) -> Option<(u64, u64)> {
+    let mut runs: Vec<u64> = history
+        .iter()
+        .filter(|x| x.machine == machine)
+        .map(|x| x.run)
+        .collect();
+
+    runs.sort_unstable();
+    runs.dedup();
+
+    let current = *runs.last()?;
+
+    let before = match baseline {
+        Some(name) => history
+            .iter()
+            .filter(|x| x.machine == machine && x.baseline.as_deref() == Some(name))
+            .map(|x| x.run)
+            .max()?,
+        None => *runs.iter().rev().nth(1)?,
+    };
+
+    Some((before, current))
+}
+
+/// Median of one part before and after.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub before_ns: f64,
+    pub after_ns: f64,
+    /// Relative change in percent, positive means slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Compare medians of matching day, part and input.
+/// Anything slower by more than `threshold` percent is a regression.
+pub fn compare(before: &[&Entry], after: &[&Entry], threshold: f64) -> Vec<Comparison> {
+    let mut comparisons = vec![];
+
+    for a in after {
+        let m = &a.measurement;
+
+        let Some(b) = before.iter().find(|b| {
+            b.measurement.day == m.day
+                && b.measurement.part == m.part
+                && b.measurement.input == m.input
+        }) else {
+            continue;
+        };
+
+        let before_ns = b.measurement.solve.median_ns;
+        let after_ns = m.solve.median_ns;
+        let change = (after_ns - before_ns) / before_ns * 100.0;
+
+        comparisons.push(Comparison {
+            day: m.day,
+            part: m.part,
+            before_ns,
+            after_ns,
+            change,
+            regressed: change > threshold,
+        });
+    }
+
+    comparisons
+}
+
+pub fn to_markdown(comparisons: &[Comparison]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Before | After | Change | Status |\n\
+         | --: | ---: | -----: | ----: | -----: | ------ |\n",
+    );
+
+    for c in comparisons {
+        table.push_str(&format!(
+            "| {:0>2} | {} | {} | {} | {:+.1}% | {} |\n",
+            c.day,
+            c.part,
+            format_ns(c.before_ns),
+            format_ns(c.after_ns),
+            c.change,
+            if c.regressed { "SLOWER" } else { "" }
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use crate::libs::bench::Stats;
+
+    fn entry(run: u64, baseline: Option<&str>, day: u8, median_ns: f64) -> Entry {
+        let stats = Stats {
+            runs: 1,
+            mean_ns: median_ns,
+            median_ns,
+            min_ns: median_ns,
+            stddev_ns: 0.0,
+        };
+
+        Entry {
+            run,
+            commit: String::from("abc1234"),
+            machine: String::from("box"),
+            baseline: baseline.map(String::from),
+            measurement: Measurement {
+                day,
+                part: 1,
+                input: String::from("personal"),
+                parse: None,
+                solve: stats,
+            },
+        }
+    }
+
+    #[test]
+    fn flags_slowdowns_against_previous_run() {
+        let history = vec![
+            entry(1, Some("v1"), 8, 1000.0),
+            entry(1, Some("v1"), 9, 1000.0),
+            entry(2, None, 8, 900.0),
+            entry(2, None, 9, 1000.0),
+            entry(3, None, 8, 1500.0),
+            entry(3, None, 9, 1050.0),
+        ];
+
+        let (before, after) = pick_runs(&history, "box", None).unwrap();
+        assert_eq!((before, after), (2, 3));
+
+        let comparisons = compare(&run_of(&history, before), &run_of(&history, after), 10.0);
+        let regressed: Vec<u8> = comparisons
+            .iter()
+            .filter(|x| x.regressed)
+            .map(|x| x.day)
+            .collect();
+
+        assert_eq!(regressed, [8]);
+
+        let (before, _) = pick_runs(&history, "box", Some("v1")).unwrap();
+        assert_eq!(before, 1);
+        assert_eq!(pick_runs(&history, "other box", None), None);
+    }
+}
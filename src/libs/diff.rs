@@ -0,0 +1,306 @@
+use std::{
+    fs, io,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use crate::libs::{
+    answer::Answer,
+    input,
+    runner::panic_message,
+    solution::{Day, DayError},
+};
+
+/// A generated input on which the fast part and the reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub seed: u64,
+    /// Already shrunk.
+    pub input: String,
+    /// The panic message or the error if the fast part gave no answer.
+    pub fast: Result<Answer, String>,
+    pub reference: Answer,
+}
+
+/// Both answers to an input, `None` if the input says nothing:
+/// it does not parse, the day has no reference, or the reference panicked.
+fn answers(day: &dyn Day, content: &str, part: u8) -> Option<(Result<Answer, String>, Answer)> {
+    let reference = panic::catch_unwind(AssertUnwindSafe(|| day.reference(content, false, part)));
+
+    let reference = reference.ok()?.ok()??;
+
+    let fast = panic::catch_unwind(AssertUnwindSafe(|| day.solve(content, false, part)));
+
+    let fast = match fast {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(DayError::Parse(_))) => return None,
+        Ok(Err(DayError::Solve(e))) => Err(e.to_string()),
+        Err(payload) => Err(panic_message(payload.as_ref())),
+    };
+
+    Some((fast, reference))
+}
+
+fn disagree(day: &dyn Day, content: &str, part: u8) -> bool {
+    answers(day, content, part).is_some_and(|(fast, reference)| fast != Ok(reference))
+}
+
+/// Drop as many items as possible while `fails` holds, big chunks first.
+fn shrink_items<F: Fn(&[String]) -> bool>(mut items: Vec<String>, fails: F) -> Vec<String> {
+    let mut chunk = items.len() / 2;
+
+    while chunk > 0 {
+        let mut i = 0;
+        let mut dropped = false;
+
+        while i < items.len() && items.len() > 1 {
+            let end = (i + chunk).min(items.len());
+            let candidate: Vec<String> = [&items[..i], &items[end..]].concat();
+
+            if !candidate.is_empty() && fails(&candidate) {
+                items = candidate;
+                dropped = true;
+            } else {
+                i += chunk;
+            }
+        }
+
+        if !dropped {
+            chunk /= 2;
+        }
+    }
+
+    items
+}
+
+/// Make a failing input smaller: drop whole lines, then comma separated items of each line.
+///
+/// `fails` must hold for `content`, and holds for whatever comes out.
+pub fn shrink<F: Fn(&str) -> bool>(content: &str, fails: F) -> String {
+    let lines: Vec<String> = content.lines().map(String::from).collect();
+    let mut lines = shrink_items(lines, |x| fails(&input::normalize(&x.join("\n"))));
+
+    for i in 0..lines.len() {
+        if !lines[i].contains(',') {
+            continue;
+        }
+
+        let items: Vec<String> = lines[i].split(',').map(String::from).collect();
+        let items = shrink_items(items, |x| {
+            let mut candidate = lines.clone();
+            candidate[i] = x.join(",");
+            fails(&input::normalize(&candidate.join("\n")))
+        });
+
+        lines[i] = items.join(",");
+    }
+
+    input::normalize(&lines.join("\n"))
+}
+
+/// Try the inputs generated from every seed in `seeds`, stop at the first disagreement.
+///
+/// Panics still go through the panic hook, swap it out to keep stderr quiet.
+pub fn diff(day: &dyn Day, part: u8, seeds: Range<u64>, size: usize) -> Option<Mismatch> {
+    for seed in seeds {
+        let content = day.generate(size, seed)?;
+
+        if !disagree(day, &content, part) {
+            continue;
+        }
+
+        let input = shrink(&content, |x| disagree(day, x, part));
+        let (fast, reference) = answers(day, &input, part).expect("Shrinking keeps it failing.");
+
+        return Some(Mismatch {
+            day: day.day(),
+            part,
+            seed,
+            input,
+            fast,
+            reference,
+        });
+    }
+
+    None
+}
+
+/// Add an expected answer to `input/samples.toml`, inside the section of its day.
+///
+/// ```
+/// use aoc_2025::libs::diff;
+///
+/// let manifest = "[day_01]\nsample.part_1.answer = \"3\"\n\n[day_02]\n";
+///
+/// assert_eq!(
+///     diff::add_to_manifest(manifest, 1, "regression_4", 2, "7"),
+///     "[day_01]\nsample.part_1.answer = \"3\"\nregression_4.part_2.answer = \"7\"\n\n[day_02]\n"
+/// );
+/// ```
+pub fn add_to_manifest(manifest: &str, day: u8, name: &str, part: u8, answer: &str) -> String {
+    let header = format!("[day_{:0>2}]", day);
+    let entry = format!("{}.part_{}.answer = {:?}", name, part, answer);
+
+    let mut lines: Vec<&str> = manifest.lines().collect();
+
+    let Some(start) = lines.iter().position(|x| x.trim() == header) else {
+        let mut manifest = manifest.trim_end().to_owned();
+        manifest.push_str(&format!("\n\n{}\n{}\n", header, entry));
+        return manifest;
+    };
+
+    // right after the last entry of the section, before the blank lines leading to the next one.
+    let mut end = lines[start + 1..]
+        .iter()
+        .position(|x| x.trim_start().starts_with('['))
+        .map_or(lines.len(), |x| start + 1 + x);
+
+    while end > start + 1 && lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+
+    lines.insert(end, &entry);
+
+    let mut manifest = lines.join("\n");
+    manifest.push('\n');
+    manifest
+}
+
+/// Save a mismatch as `input/day_NN_regression_<seed>.txt`, with the reference answer
+/// in `manifest` so `cargo test` keeps checking it. Returns the input name.
+pub fn save(dir: &Path, manifest: &Path, mismatch: &Mismatch) -> io::Result<String> {
+    let mut name = format!("regression_{}", mismatch.seed);
+    let mut k = 2;
+
+    // both parts may fail on the same seed, each with its own shrunk input.
+    while input::path_in(dir, mismatch.day, &name).exists() {
+        name = format!("regression_{}_{}", mismatch.seed, k);
+        k += 1;
+    }
+
+    fs::write(input::path_in(dir, mismatch.day, &name), &mismatch.input)?;
+
+    let content = match fs::read_to_string(manifest) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    let content = add_to_manifest(
+        &content,
+        mismatch.day,
+        &name,
+        mismatch.part,
+        &mismatch.reference.to_string(),
+    );
+    fs::write(manifest, content)?;
+
+    Ok(name)
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use crate::libs::{
+        fetch::aoc_test::scratch_dir,
+        generate::{self, Rng},
+        solution::{Erased, ParseError, Solution, SolveError},
+    };
+    use proptest::prelude::*;
+    use rand::RngExt;
+
+    /// Forgets about numbers past 50.
+    struct Sloppy;
+
+    impl Solution for Sloppy {
+        const DAY: u8 = 42;
+
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .map(|x| x.parse().map_err(|_| ParseError::new("NaN.")))
+                .collect()
+        }
+
+        fn part_1(input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(Answer::from(
+                input.iter().filter(|&&x| x <= 50).sum::<i64>(),
+            ))
+        }
+
+        fn part_2(_input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(Answer::from(0))
+        }
+
+        fn reference_1(input: &Self::Input) -> Option<Answer> {
+            Some(Answer::from(input.iter().sum::<i64>()))
+        }
+
+        fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+            let numbers: Vec<String> = (0..size)
+                .map(|_| rng.random_range(0..60_i64).to_string())
+                .collect();
+            Some(generate::lines(numbers))
+        }
+    }
+
+    #[test]
+    fn finds_shrinks_and_saves() {
+        let solver = Erased::<Sloppy>::new();
+
+        // part 2 has no reference, nothing to say.
+        assert_eq!(diff(&solver, 2, 0..100, 10), None);
+
+        let mismatch = diff(&solver, 1, 0..100, 10).unwrap();
+        let number: i64 = mismatch.input.trim().parse().unwrap();
+
+        assert!(number > 50);
+        assert_eq!(mismatch.fast, Ok(Answer::from(0)));
+        assert_eq!(mismatch.reference, Answer::from(number));
+
+        let dir = scratch_dir("diff");
+        fs::create_dir_all(&dir).unwrap();
+        let manifest = dir.join("samples.toml");
+
+        assert_eq!(
+            save(&dir, &manifest, &mismatch).unwrap(),
+            format!("regression_{}", mismatch.seed)
+        );
+        assert_eq!(
+            save(&dir, &manifest, &mismatch).unwrap(),
+            format!("regression_{}_2", mismatch.seed)
+        );
+
+        let content = fs::read_to_string(&manifest).unwrap();
+        let table: toml::Table = toml::from_str(&content).unwrap();
+
+        assert_eq!(table["day_42"].as_table().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn shrinks_to_the_culprit() {
+        let content = "1,2,3\n4,5,66\n7,8,9\n";
+        let shrunk = shrink(content, |x| x.contains("66"));
+
+        assert_eq!(shrunk, "66\n");
+    }
+
+    proptest! {
+        #[test]
+        fn shrinking_keeps_it_failing(content in "([0-9]{1,2}(,[0-9]{1,2}){0,3}\n){1,8}") {
+            let fails = |x: &str| x.contains('7');
+            prop_assume!(fails(&content));
+
+            let shrunk = shrink(&content, fails);
+
+            prop_assert!(fails(&shrunk));
+            prop_assert!(shrunk.len() <= content.len());
+            prop_assert_eq!(shrunk.lines().count(), 1);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::libs::input;
+
+/// Where the real puzzles live.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Fallback file holding the session cookie, relative to the working directory.
+pub const SESSION_FILE: &str = ".aoc_session";
+
+/// AoC asks automated tools to say who they are.
+const USER_AGENT: &str = "github.com/swyrin/aoc-2025 by swyrin@akvns.org";
+
+/// File remembering when we last talked to the server, inside the input directory.
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither [`SESSION_ENV`] nor [`SESSION_FILE`] has a token.
+    MissingSession,
+    /// The server answered, but not with a 2xx.
+    Status(u16),
+    /// Could not reach the server at all.
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "No session token, set {} or write it into {}.",
+                SESSION_ENV, SESSION_FILE
+            ),
+            FetchError::Status(code) => write!(f, "Server answered with status {}.", code),
+            FetchError::Http(e) => write!(f, "Request failed: {}.", e),
+            FetchError::Io(e) => write!(f, "I/O error: {}.", e),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<ureq::Error> for FetchError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::StatusCode(code) => FetchError::Status(code),
+            e => FetchError::Http(e),
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+/// Read the session token, environment variable first, then the config file.
+pub fn session_token(config_file: &Path) -> Option<String> {
+    let token = match env::var(SESSION_ENV) {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(config_file).ok()?,
+    };
+
+    let token = token.trim();
+
+    if token.is_empty() {
+        None
+    } else {
+        Some(token.to_owned())
+    }
+}
+
+/// Whether the input came from the cache or the network.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Small blocking client for the puzzle server.
+///
+/// Inputs are cached as `day_NN_personal.txt` inside the input directory,
+/// and requests are spaced at least `min_interval` apart, even across runs.
+pub struct Client {
+    base_url: String,
+    session: String,
+    input_dir: PathBuf,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            input_dir: PathBuf::from(input::INPUT_DIR),
+            min_interval: Duration::from_secs(15),
+            agent: ureq::Agent::new_with_defaults(),
+        }
+    }
+
+    pub fn input_dir(mut self, dir: &Path) -> Self {
+        self.input_dir = dir.to_path_buf();
+        self
+    }
+
+    pub fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    /// Download the personal input of a day, unless it is already cached.
+    pub fn fetch_input(&self, year: u16, day: u8, force: bool) -> Result<Fetched, FetchError> {
+        let path = input::path_in(&self.input_dir, day, "personal");
+
+        if !force && path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = self.url(&format!("/{}/day/{}/input", year, day));
+        let content = self.get(&url)?;
+
+        fs::create_dir_all(&self.input_dir)?;
+        fs::write(&path, content)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Full URL of a path on the server, i.e. `/2025/day/5/input`.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn get(&self, url: &str) -> Result<String, FetchError> {
+        self.throttle()?;
+
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()?;
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    pub(crate) fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        self.throttle()?;
+
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())?;
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    /// Sleep until `min_interval` has passed since the last request, then stamp the current one.
+    fn throttle(&self) -> Result<(), FetchError> {
+        let stamp = self.input_dir.join(LAST_REQUEST_FILE);
+
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|x| x.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+
+        if let Some(last) = last {
+            let next = last + self.min_interval;
+            let now = now();
+
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+
+        fs::create_dir_all(&self.input_dir)?;
+        fs::write(&stamp, now().as_millis().to_string())?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards?")
+}
+
+#[cfg(test)]
+pub(crate) mod aoc_test {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    /// Serve `responses` in order on a local port, handing every raw request back.
+    pub(crate) fn stub_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut payload = vec![0; content_length];
+                reader.read_exact(&mut payload).unwrap();
+                request.push_str(&String::from_utf8(payload).unwrap());
+
+                tx.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-2025-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_then_caches() {
+        let (url, requests) = stub_server(vec![(200, "1-3\n\n2\n")]);
+        let dir = scratch_dir("fetch-cache");
+
+        let client = Client::new(&url, "cookie")
+            .input_dir(&dir)
+            .min_interval(Duration::ZERO);
+
+        let path = input::path_in(&dir, 5, "personal");
+
+        assert_eq!(
+            client.fetch_input(2025, 5, false).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1-3\n\n2\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/5/input "));
+        assert!(request.contains("session=cookie"));
+
+        assert_eq!(
+            client.fetch_input(2025, 5, false).unwrap(),
+            Fetched::Cached(path)
+        );
+    }
+
+    #[test]
+    fn waits_between_requests_across_runs() {
+        let (url, requests) = stub_server(vec![(200, "1\n"), (200, "2\n")]);
+        let dir = scratch_dir("fetch-throttle");
+        let interval = Duration::from_millis(300);
+
+        let client = Client::new(&url, "cookie")
+            .input_dir(&dir)
+            .min_interval(interval);
+
+        // a previous run just made a request. Stamps are in whole milliseconds, hence the slack.
+        let start = std::time::Instant::now();
+        let slack = Duration::from_millis(2);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(LAST_REQUEST_FILE), now().as_millis().to_string()).unwrap();
+
+        client.fetch_input(2025, 1, false).unwrap();
+        assert!(start.elapsed() + slack >= interval);
+
+        // forced, so past the cache, and throttled again.
+        client.fetch_input(2025, 1, true).unwrap();
+        assert!(start.elapsed() + slack >= interval * 2);
+
+        assert_eq!(requests.iter().take(2).count(), 2);
+        assert_eq!(
+            fs::read_to_string(input::path_in(&dir, 1, "personal")).unwrap(),
+            "2\n"
+        );
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, _requests) = stub_server(vec![(400, "Please log in.")]);
+        let dir = scratch_dir("fetch-status");
+
+        let client = Client::new(&url, "expired")
+            .input_dir(&dir)
+            .min_interval(Duration::ZERO);
+
+        assert!(matches!(
+            client.fetch_input(2025, 1, false),
+            Err(FetchError::Status(400))
+        ));
+        assert!(!input::path_in(&dir, 1, "personal").exists());
+    }
+}
@@ -0,0 +1,25 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Randomness for input generators, seeded so every input can be made again.
+pub type Rng = ChaCha8Rng;
+
+pub fn rng(seed: u64) -> Rng {
+    Rng::seed_from_u64(seed)
+}
+
+/// One item per line, with the trailing newline every input file has.
+pub fn lines<I, S>(items: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut content = String::new();
+
+    for item in items {
+        content.push_str(item.as_ref());
+        content.push('\n');
+    }
+
+    content
+}
@@ -0,0 +1,557 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "microlp")]
+use good_lp::{
+    Expression, IntoAffineExpression, Solution as _, SolverModel, Variable, variable, variables,
+};
+
+/// Fewest presses in total such that, for every counter, the presses of the buttons
+/// touching it add up to its target. Day 10 part 2, as an integer program.
+#[derive(Debug, Clone, Copy)]
+pub struct Problem<'a> {
+    /// Counters each button touches, all below `target.len()`.
+    pub buttons: &'a [Vec<u32>],
+    pub target: &'a [u32],
+}
+
+impl Problem<'_> {
+    /// Whether `presses` bring every counter to its target, optimal or not.
+    pub fn satisfied_by(&self, presses: &[u32]) -> bool {
+        let mut counters = vec![0; self.target.len()];
+
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for &x in button {
+                counters[x as usize] += count;
+            }
+        }
+
+        presses.len() == self.buttons.len() && counters == self.target
+    }
+
+    /// How often a button can be pressed at most, before one of its counters overshoots.
+    #[cfg(feature = "bnb")]
+    fn cap(&self, button: usize) -> u32 {
+        self.buttons[button]
+            .iter()
+            .map(|&x| self.target[x as usize])
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+/// Ways to solve a [`Problem`]. Each can be left out of the build with the cargo feature
+/// of the same name, except [`Backend::Exact`] which doubles as the reference of day 10.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// The simplex and branch and bound of `good_lp`, in floating point.
+    Microlp,
+    /// Split every counter among its buttons, integers only. Slow.
+    Exact,
+    /// Gaussian elimination, then branch and bound over the buttons that stay free.
+    Bnb,
+}
+
+impl Backend {
+    /// In the order of the discriminants.
+    pub const ALL: [Backend; 3] = [Backend::Microlp, Backend::Exact, Backend::Bnb];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Microlp => "microlp",
+            Backend::Exact => "exact",
+            Backend::Bnb => "bnb",
+        }
+    }
+
+    /// Whether this one got built in.
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Microlp => cfg!(feature = "microlp"),
+            Backend::Exact => true,
+            Backend::Bnb => cfg!(feature = "bnb"),
+        }
+    }
+
+    pub fn available() -> Vec<Backend> {
+        Self::ALL.into_iter().filter(|x| x.is_available()).collect()
+    }
+
+    /// Presses per button, fewest in total. `None` if the target is out of reach.
+    ///
+    /// Panics if the backend is not built in.
+    pub fn solve(self, problem: Problem) -> Option<Vec<u32>> {
+        match self {
+            #[cfg(feature = "microlp")]
+            Backend::Microlp => microlp(problem),
+            Backend::Exact => Exact::solve(problem),
+            #[cfg(feature = "bnb")]
+            Backend::Bnb => Bnb::solve(problem),
+            #[allow(unreachable_patterns)]
+            other => panic!("{} is not built in, enable its cargo feature.", other),
+        }
+    }
+}
+
+/// The fastest one that got built in.
+impl Default for Backend {
+    fn default() -> Self {
+        [Backend::Microlp, Backend::Bnb, Backend::Exact]
+            .into_iter()
+            .find(|x| x.is_available())
+            .unwrap_or(Backend::Exact)
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// ```
+/// use aoc_2025::libs::ilp::Backend;
+///
+/// assert_eq!("exact".parse::<Backend>(), Ok(Backend::Exact));
+/// assert!("simplex".parse::<Backend>().is_err());
+/// ```
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let names: Vec<&str> = Self::ALL.iter().map(|x| x.name()).collect();
+
+        match Self::ALL.into_iter().find(|x| x.name() == s) {
+            Some(backend) if backend.is_available() => Ok(backend),
+            Some(backend) => Err(format!(
+                "{} is not built in, enable the `{}` feature",
+                backend, backend
+            )),
+            None => Err(format!("expected one of {}", names.join(", "))),
+        }
+    }
+}
+
+/// What one backend made of a problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub backend: Backend,
+    pub presses: Option<Vec<u32>>,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    pub fn total(&self) -> Option<u64> {
+        self.presses
+            .as_ref()
+            .map(|x| x.iter().map(|&x| x as u64).sum())
+    }
+}
+
+/// Solve `problem` with each of `backends`, in that order.
+pub fn compare(problem: Problem, backends: &[Backend]) -> Vec<Outcome> {
+    backends
+        .iter()
+        .map(|&backend| {
+            let now = Instant::now();
+            let presses = backend.solve(problem);
+
+            Outcome {
+                backend,
+                presses,
+                elapsed: now.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// Whether every outcome has valid presses and the same total, or none found any.
+pub fn agree(problem: Problem, outcomes: &[Outcome]) -> bool {
+    let valid = outcomes.iter().all(|x| {
+        x.presses
+            .as_ref()
+            .is_none_or(|presses| problem.satisfied_by(presses))
+    });
+
+    valid && outcomes.windows(2).all(|x| x[0].total() == x[1].total())
+}
+
+#[cfg(feature = "microlp")]
+fn microlp(problem: Problem) -> Option<Vec<u32>> {
+    let mut vars = variables!();
+
+    // basically, we do an [A | x] = B where A is the button press toggles, B is the required count
+    // the result will be vector x which is the number of each button touches.
+    let presses: Vec<Variable> = (0..problem.buttons.len())
+        .map(|_| vars.add(variable().min(0).integer()))
+        .collect();
+
+    let mut optimization = good_lp::microlp(vars.minimise(presses.iter().sum::<Expression>()));
+    let mut expressions = vec![0.into_expression(); problem.target.len()];
+
+    for (button, &v) in problem.buttons.iter().zip(&presses) {
+        for &x in button {
+            expressions[x as usize] += v;
+        }
+    }
+
+    for (e, &j) in expressions.into_iter().zip(problem.target) {
+        optimization.add_constraint(e.eq(j as f64));
+    }
+
+    let solution = optimization.solve().ok()?;
+
+    // the solver works in floating point, do not let 2.9999 become 2.
+    Some(
+        presses
+            .iter()
+            .map(|&v| solution.value(v).round() as u32)
+            .collect(),
+    )
+}
+
+/// Settles the counter with the fewest free buttons first, trying every way to split
+/// its count among them.
+struct Exact<'a> {
+    buttons: &'a [Vec<u32>],
+    /// Buttons whose presses are not decided yet.
+    free: Vec<bool>,
+    /// What every counter still needs.
+    left: Vec<u32>,
+    presses: Vec<u32>,
+    best: Option<(u32, Vec<u32>)>,
+}
+
+impl Exact<'_> {
+    fn solve(problem: Problem) -> Option<Vec<u32>> {
+        let mut search = Exact {
+            buttons: problem.buttons,
+            free: vec![true; problem.buttons.len()],
+            left: problem.target.to_vec(),
+            presses: vec![0; problem.buttons.len()],
+            best: None,
+        };
+
+        search.fewest(0);
+        search.best.map(|x| x.1)
+    }
+
+    /// On top of `pressed` so far.
+    fn fewest(&mut self, pressed: u32) {
+        let highest = self.left.iter().copied().max().unwrap_or(0);
+
+        // one press lowers every counter by one at most.
+        if self.best.as_ref().is_some_and(|x| pressed + highest >= x.0) {
+            return;
+        }
+
+        if highest == 0 {
+            self.best = Some((pressed, self.presses.clone()));
+            return;
+        }
+
+        let touching = |c: usize| -> Vec<usize> {
+            (0..self.buttons.len())
+                .filter(|&b| self.free[b] && self.buttons[b].contains(&(c as u32)))
+                .collect()
+        };
+
+        let Some(counter) = (0..self.left.len())
+            .filter(|&c| self.left[c] > 0)
+            .min_by_key(|&c| touching(c).len())
+        else {
+            return;
+        };
+
+        let buttons = touching(counter);
+
+        if buttons.is_empty() {
+            return;
+        }
+
+        for &b in &buttons {
+            self.free[b] = false;
+        }
+
+        self.split(&buttons, self.left[counter], pressed);
+
+        for &b in &buttons {
+            self.free[b] = true;
+        }
+    }
+
+    /// Press `buttons[0]` anywhere from 0 to `left` times, the rest of `buttons` share what remains.
+    fn split(&mut self, buttons: &[usize], left: u32, pressed: u32) {
+        let Some((&b, rest)) = buttons.split_first() else {
+            if left == 0 {
+                self.fewest(pressed);
+            }
+
+            return;
+        };
+
+        // the last button takes whatever is left.
+        let counts = if rest.is_empty() {
+            left..=left
+        } else {
+            0..=left
+        };
+
+        for count in counts {
+            if self.buttons[b]
+                .iter()
+                .any(|&x| self.left[x as usize] < count)
+            {
+                break;
+            }
+
+            for &x in &self.buttons[b] {
+                self.left[x as usize] -= count;
+            }
+
+            self.presses[b] = count;
+            self.split(rest, left - count, pressed + count);
+
+            for &x in &self.buttons[b] {
+                self.left[x as usize] += count;
+            }
+        }
+
+        self.presses[b] = 0;
+    }
+}
+
+/// Every pivot button is a function of the free ones after elimination, so only the free
+/// ones get enumerated, up to their caps.
+#[cfg(feature = "bnb")]
+struct Bnb<'a> {
+    /// One per counter, the button coefficients then the target. Reduced.
+    rows: Vec<Vec<i64>>,
+    /// `(row, button)` of every pivot.
+    pivots: Vec<(usize, usize)>,
+    free: Vec<usize>,
+    caps: Vec<u32>,
+    buttons: &'a [Vec<u32>],
+    /// What every counter still needs, given the free buttons decided so far.
+    left: Vec<u32>,
+    presses: Vec<u32>,
+    best: Option<(u64, Vec<u32>)>,
+}
+
+#[cfg(feature = "bnb")]
+impl Bnb<'_> {
+    fn solve(problem: Problem) -> Option<Vec<u32>> {
+        let n = problem.buttons.len();
+
+        let mut rows: Vec<Vec<i64>> = (0..problem.target.len() as u32)
+            .map(|c| {
+                let mut row: Vec<i64> = problem
+                    .buttons
+                    .iter()
+                    .map(|x| x.contains(&c) as i64)
+                    .collect();
+
+                row.push(problem.target[c as usize] as i64);
+                row
+            })
+            .collect();
+
+        let mut pivots = vec![];
+
+        for button in 0..n {
+            let r = pivots.len();
+
+            let Some(p) = (r..rows.len()).find(|&i| rows[i][button] != 0) else {
+                continue;
+            };
+
+            rows.swap(r, p);
+            let pivot = rows[r].clone();
+
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i == r || row[button] == 0 {
+                    continue;
+                }
+
+                // integers only, then divided back down so nothing grows.
+                let factor = row[button];
+
+                for (x, &y) in row.iter_mut().zip(&pivot) {
+                    *x = *x * pivot[button] - y * factor;
+                }
+
+                let divisor = row.iter().fold(0, |g, &x| gcd(g, x));
+
+                if divisor > 1 {
+                    row.iter_mut().for_each(|x| *x /= divisor);
+                }
+            }
+
+            pivots.push((r, button));
+        }
+
+        // 0 = something.
+        if rows[pivots.len()..].iter().any(|x| x[n] != 0) {
+            return None;
+        }
+
+        let mut bnb = Bnb {
+            rows,
+            free: (0..n)
+                .filter(|&b| pivots.iter().all(|x| x.1 != b))
+                .collect(),
+            pivots,
+            caps: (0..n).map(|b| problem.cap(b)).collect(),
+            buttons: problem.buttons,
+            left: problem.target.to_vec(),
+            presses: vec![0; n],
+            best: None,
+        };
+
+        bnb.assign(0, 0);
+        bnb.best.map(|x| x.1)
+    }
+
+    /// Try every count of the `k`-th free button, `pressed` in total so far.
+    fn assign(&mut self, k: usize, pressed: u64) {
+        let highest = self.left.iter().copied().max().unwrap_or(0) as u64;
+
+        // one press lowers every counter by one at most.
+        if self.best.as_ref().is_some_and(|x| pressed + highest >= x.0) {
+            return;
+        }
+
+        let Some(&b) = self.free.get(k) else {
+            self.settle(pressed);
+            return;
+        };
+
+        for count in 0..=self.caps[b] {
+            // one more press would overshoot, so would every count after it.
+            if count > 0 && self.buttons[b].iter().any(|&x| self.left[x as usize] == 0) {
+                break;
+            }
+
+            if count > 0 {
+                self.buttons[b]
+                    .iter()
+                    .for_each(|&x| self.left[x as usize] -= 1);
+            }
+
+            self.presses[b] = count;
+            self.assign(k + 1, pressed + count as u64);
+        }
+
+        for &x in &self.buttons[b] {
+            self.left[x as usize] += self.presses[b];
+        }
+
+        self.presses[b] = 0;
+    }
+
+    /// Work out the pivot buttons from the free ones, keep it if they are all whole and in range.
+    fn settle(&mut self, mut pressed: u64) {
+        let n = self.presses.len();
+
+        for &(r, b) in &self.pivots {
+            let row = &self.rows[r];
+
+            let rest = row[n]
+                - self
+                    .free
+                    .iter()
+                    .map(|&f| row[f] * self.presses[f] as i64)
+                    .sum::<i64>();
+
+            if rest % row[b] != 0 {
+                return;
+            }
+
+            let count = rest / row[b];
+
+            if count < 0 || count > self.caps[b] as i64 {
+                return;
+            }
+
+            self.presses[b] = count as u32;
+            pressed += count as u64;
+        }
+
+        if self.best.as_ref().is_none_or(|x| pressed < x.0) {
+            self.best = Some((pressed, self.presses.clone()));
+        }
+    }
+}
+
+#[cfg(feature = "bnb")]
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Random buttons over a few counters, and a target some presses actually reach.
+    fn problems() -> impl Strategy<Value = (Vec<Vec<u32>>, Vec<u32>)> {
+        (1_u32..6)
+            .prop_flat_map(|counters| {
+                let button = prop::collection::btree_set(0..counters, 1..=counters as usize);
+
+                (
+                    Just(counters),
+                    prop::collection::vec(button, 1..7),
+                    prop::collection::vec(0_u32..5, 7),
+                )
+            })
+            .prop_map(|(counters, buttons, presses)| {
+                let buttons: Vec<Vec<u32>> = buttons
+                    .into_iter()
+                    .map(|x| x.into_iter().collect())
+                    .collect();
+
+                let mut target = vec![0; counters as usize];
+
+                for (button, count) in buttons.iter().zip(presses) {
+                    for &x in button {
+                        target[x as usize] += count;
+                    }
+                }
+
+                (buttons, target)
+            })
+    }
+
+    #[test]
+    fn nobody_reaches_the_unreachable() {
+        let buttons = [vec![0, 1]];
+        let problem = Problem {
+            buttons: &buttons,
+            target: &[1, 2],
+        };
+
+        for outcome in compare(problem, &Backend::available()) {
+            assert_eq!(outcome.presses, None, "{}", outcome.backend);
+        }
+    }
+
+    #[test]
+    fn picks_what_is_there() {
+        assert!(Backend::default().is_available());
+    }
+
+    proptest! {
+        #[test]
+        fn backends_agree((buttons, target) in problems()) {
+            let problem = Problem { buttons: &buttons, target: &target };
+            let outcomes = compare(problem, &Backend::available());
+
+            prop_assert!(agree(problem, &outcomes), "{:?}", outcomes);
+            prop_assert!(outcomes.iter().all(|x| x.presses.is_some()));
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// The directory holding every input file.
+pub const INPUT_DIR: &str = "input";
+
+/// Expected answers of the extra inputs, turned into tests by `build.rs`.
+pub const SAMPLES_FILE: &str = "input/samples.toml";
+
+/// Location of an input file inside `dir`.
+///
+/// ```
+/// use std::path::Path;
+/// use aoc_2025::libs::input;
+///
+/// let path = input::path_in(Path::new("input"), 5, "personal");
+///
+/// assert_eq!(path, Path::new("input").join("day_05_personal.txt"));
+/// ```
+pub fn path_in(dir: &Path, day: u8, name: &str) -> PathBuf {
+    dir.join(format!("day_{:0>2}_{}.txt", day, name))
+}
+
+/// Location of an input file inside [`INPUT_DIR`], i.e. `input/day_05_personal.txt`.
+pub fn path(day: u8, name: &str) -> PathBuf {
+    path_in(Path::new(INPUT_DIR), day, name)
+}
+
+/// Names of every input of a day inside `dir`, sorted.
+///
+/// `day_05_personal.txt` and `day_05_sample.txt` give `["personal", "sample"]`.
+pub fn names_in(dir: &Path, day: u8) -> io::Result<Vec<String>> {
+    let prefix = format!("day_{:0>2}_", day);
+    let mut names = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let file_name = entry?.file_name();
+
+        let name = file_name
+            .to_str()
+            .and_then(|x| x.strip_prefix(&prefix))
+            .and_then(|x| x.strip_suffix(".txt"));
+
+        if let Some(name) = name {
+            names.push(name.to_owned());
+        }
+    }
+
+    names.sort();
+
+    Ok(names)
+}
+
+/// Sample inputs come with the puzzle text, everything else is ours.
+pub fn is_sample(name: &str) -> bool {
+    name.starts_with("sample")
+}
+
+/// Clean up whatever editors and clipboards do to a file:
+/// drop the BOM, turn CRLF into LF, and end with exactly one newline.
+///
+/// Trailing spaces stay, day 06 reads its input column by column.
+///
+/// ```
+/// use aoc_2025::libs::input;
+///
+/// assert_eq!(input::normalize("\u{feff}1 2\r\n3  \r\n\r\n"), "1 2\n3  \n");
+/// assert_eq!(input::normalize("1"), "1\n");
+/// assert_eq!(input::normalize("\n\n"), "");
+/// assert_eq!(input::normalize("a\r\r\nb"), "a\nb\n");
+/// ```
+pub fn normalize(raw: &str) -> String {
+    let content = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    // "\r\r\n" happens too, after a CRLF file went through a CRLF converting tool.
+    let mut content: String = content
+        .split('\n')
+        .map(|x| x.trim_end_matches('\r'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    content.truncate(content.trim_end_matches('\n').len());
+
+    if !content.is_empty() {
+        content.push('\n');
+    }
+
+    content
+}
+
+/// Read and [`normalize`] an input file.
+pub fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map(|x| normalize(&x))
+}
+
+/// Where an input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` is stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Read everything, [`normalize`]d.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw)?;
+                Ok(normalize(&raw))
+            }
+            Source::File(path) => read(path),
+        }
+    }
+
+    /// Read line by line instead, nothing gets normalized.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn normalize_is_idempotent(raw in "\u{feff}?[ab \r\n]{0,20}") {
+            let once = normalize(&raw);
+
+            prop_assert_eq!(normalize(&once), once.clone());
+            prop_assert!(!once.contains("\r\n"));
+            prop_assert!(once.is_empty() || (once.ends_with('\n') && !once.ends_with("\n\n")));
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::cmp;
+
+/// A static interval tree over inclusive ranges, i.e. the fresh IDs of day 05.
+///
+/// Membership and counting go through the merged ranges, "which ranges contain x" goes
+/// through the original ones sorted by start, as an implicit tree with the largest end
+/// of every subtree.
+///
+/// ```
+/// use aoc_2025::libs::interval::IntervalTree;
+///
+/// let tree = IntervalTree::new(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+///
+/// assert!(tree.contains(11));
+/// assert!(!tree.contains(8));
+/// assert_eq!(tree.stab(13), [1, 3]);
+/// assert_eq!(tree.count(1, 12), 6);
+/// assert_eq!(tree.total(), 14);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalTree {
+    /// Sorted, disjoint and never touching.
+    merged: Vec<(i64, i64)>,
+    /// How many IDs the merged ranges before each one hold.
+    before: Vec<u128>,
+    /// `(start, end, index in the input)`, sorted.
+    by_start: Vec<(i64, i64, usize)>,
+    /// Largest end in the subtree rooted at each position of `by_start`.
+    max_end: Vec<i64>,
+}
+
+/// Number of integers in `a..=b`, which may well not fit in an `i64`.
+fn width(a: i64, b: i64) -> u128 {
+    (b as i128 - a as i128 + 1) as u128
+}
+
+impl IntervalTree {
+    /// Empty ranges, where the head is past the tail, hold nothing and get dropped.
+    pub fn new(ranges: &[(i64, i64)]) -> Self {
+        let mut by_start: Vec<(i64, i64, usize)> = ranges
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.0 <= x.1)
+            .map(|(i, &(a, b))| (a, b, i))
+            .collect();
+
+        by_start.sort_unstable();
+
+        let mut merged: Vec<(i64, i64)> = vec![];
+
+        for &(a, b, _) in &by_start {
+            match merged.last_mut() {
+                Some(last) if a <= last.1.saturating_add(1) => last.1 = cmp::max(last.1, b),
+                _ => merged.push((a, b)),
+            }
+        }
+
+        let mut before = Vec::with_capacity(merged.len());
+        let mut total = 0;
+
+        for &(a, b) in &merged {
+            before.push(total);
+            total += width(a, b);
+        }
+
+        let mut tree = Self {
+            merged,
+            before,
+            max_end: vec![i64::MIN; by_start.len()],
+            by_start,
+        };
+
+        tree.build(0, tree.by_start.len());
+        tree
+    }
+
+    fn build(&mut self, lo: usize, hi: usize) -> i64 {
+        if lo >= hi {
+            return i64::MIN;
+        }
+
+        let mid = (lo + hi) / 2;
+        let left = self.build(lo, mid);
+        let right = self.build(mid + 1, hi);
+
+        self.max_end[mid] = left.max(right).max(self.by_start[mid].1);
+        self.max_end[mid]
+    }
+
+    /// The merged ranges, sorted.
+    pub fn merged(&self) -> &[(i64, i64)] {
+        &self.merged
+    }
+
+    /// Position of the merged range that could hold `x`, the last one starting at or before it.
+    fn last_starting_by(&self, x: i64) -> Option<usize> {
+        self.merged.partition_point(|r| r.0 <= x).checked_sub(1)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.last_starting_by(x)
+            .is_some_and(|i| x <= self.merged[i].1)
+    }
+
+    /// Indices of every input range containing `x`, in input order.
+    pub fn stab(&self, x: i64) -> Vec<usize> {
+        let mut found = vec![];
+        self.stab_in(0, self.by_start.len(), x, &mut found);
+
+        found.sort_unstable();
+        found
+    }
+
+    fn stab_in(&self, lo: usize, hi: usize, x: i64, found: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+
+        // everything down there ends before x.
+        if self.max_end[mid] < x {
+            return;
+        }
+
+        self.stab_in(lo, mid, x, found);
+
+        let (a, b, i) = self.by_start[mid];
+
+        // the right side starts even later.
+        if x < a {
+            return;
+        }
+
+        if x <= b {
+            found.push(i);
+        }
+
+        self.stab_in(mid + 1, hi, x, found);
+    }
+
+    /// How many IDs up to `x` are fresh.
+    fn count_up_to(&self, x: i64) -> u128 {
+        match self.last_starting_by(x) {
+            None => 0,
+            Some(i) => {
+                let (a, b) = self.merged[i];
+                self.before[i] + width(a, cmp::min(x, b))
+            }
+        }
+    }
+
+    /// How many IDs in `a..=b` are fresh.
+    pub fn count(&self, a: i64, b: i64) -> u128 {
+        if a > b {
+            return 0;
+        }
+
+        match a.checked_sub(1) {
+            Some(x) => self.count_up_to(b) - self.count_up_to(x),
+            None => self.count_up_to(b),
+        }
+    }
+
+    /// How many IDs are fresh at all.
+    pub fn total(&self) -> u128 {
+        match self.merged.last() {
+            Some(&(a, b)) => self.before[self.merged.len() - 1] + width(a, b),
+            None => 0,
+        }
+    }
+
+    /// [`IntervalTree::contains`] for every ID, sorted first so it is one sweep
+    /// over the merged ranges.
+    pub fn contains_all(&self, ids: &[i64]) -> Vec<bool> {
+        let mut order: Vec<usize> = (0..ids.len()).collect();
+        order.sort_unstable_by_key(|&i| ids[i]);
+
+        let mut fresh = vec![false; ids.len()];
+        let mut ranges = self.merged.iter().peekable();
+
+        for i in order {
+            while ranges.next_if(|r| r.1 < ids[i]).is_some() {}
+
+            fresh[i] = ranges.peek().is_some_and(|r| r.0 <= ids[i]);
+        }
+
+        fresh
+    }
+
+    /// How many of `ids` are fresh, duplicates counted each time.
+    pub fn count_contained(&self, ids: &[i64]) -> usize {
+        self.contains_all(ids).into_iter().filter(|&x| x).count()
+    }
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn ranges() -> impl Strategy<Value = Vec<(i64, i64)>> {
+        prop::collection::vec((-50_i64..50, -5_i64..20), 0..20)
+            .prop_map(|x| x.into_iter().map(|(a, w)| (a, a + w)).collect())
+    }
+
+    fn naive_stab(ranges: &[(i64, i64)], x: i64) -> Vec<usize> {
+        (0..ranges.len())
+            .filter(|&i| ranges[i].0 <= x && x <= ranges[i].1)
+            .collect()
+    }
+
+    #[test]
+    fn handles_the_extremes() {
+        let tree = IntervalTree::new(&[(i64::MIN, i64::MAX)]);
+
+        assert_eq!(tree.total(), 1 << 64);
+        assert_eq!(tree.count(i64::MIN, -1), 1 << 63);
+        assert!(tree.contains(i64::MAX));
+
+        assert_eq!(IntervalTree::new(&[]).stab(0), Vec::<usize>::new());
+        assert_eq!(IntervalTree::new(&[]).count(i64::MIN, i64::MAX), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn same_as_checking_every_range(
+            ranges in ranges(),
+            xs in prop::collection::vec(-60_i64..80, 0..30),
+        ) {
+            let tree = IntervalTree::new(&ranges);
+            let fresh = tree.contains_all(&xs);
+
+            for (i, &x) in xs.iter().enumerate() {
+                let stabbed = naive_stab(&ranges, x);
+
+                prop_assert_eq!(tree.stab(x), stabbed.clone());
+                prop_assert_eq!(tree.contains(x), !stabbed.is_empty());
+                prop_assert_eq!(fresh[i], !stabbed.is_empty());
+            }
+        }
+
+        #[test]
+        fn counts_like_checking_every_id(ranges in ranges(), a in -60_i64..80, w in -3_i64..80) {
+            let tree = IntervalTree::new(&ranges);
+            let naive = (a..=a + w).filter(|&x| tree.contains(x)).count() as u128;
+
+            prop_assert_eq!(tree.count(a, a + w), naive);
+            prop_assert_eq!(tree.total(), tree.count(-100, 100));
+        }
+    }
+}
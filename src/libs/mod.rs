@@ -1 +1,18 @@
-pub mod range;
\ No newline at end of file
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod bench_history;
+pub mod diff;
+pub mod fetch;
+pub mod generate;
+pub mod ilp;
+pub mod input;
+pub mod interval;
+pub mod poly;
+pub mod range;
+pub mod runner;
+pub mod sandbox;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod verify;
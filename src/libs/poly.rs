@@ -0,0 +1,265 @@
+/// A lattice point, i.e. a tile of day 09.
+pub type Point = (i64, i64);
+
+/// Where a point is relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Every edge, the closing one included.
+fn edges(poly: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    (0..poly.len()).map(|i| (poly[i], poly[(i + 1) % poly.len()]))
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Twice the signed area, by the shoelace formula. Positive when the corners go
+/// counter-clockwise with y up, clockwise with y down like the puzzles.
+///
+/// ```
+/// use aoc_2025::libs::poly;
+///
+/// assert_eq!(poly::twice_area(&[(0, 0), (3, 0), (3, 2), (0, 2)]), 12);
+/// assert_eq!(poly::twice_area(&[(0, 0), (0, 2), (3, 2), (3, 0)]), -12);
+/// ```
+pub fn twice_area(poly: &[Point]) -> i128 {
+    edges(poly)
+        .map(|((x1, y1), (x2, y2))| x1 as i128 * y2 as i128 - x2 as i128 * y1 as i128)
+        .sum()
+}
+
+/// Lattice points on the edges.
+pub fn boundary_points(poly: &[Point]) -> u128 {
+    edges(poly)
+        .map(|((x1, y1), (x2, y2))| gcd(x1.abs_diff(x2), y1.abs_diff(y2)) as u128)
+        .sum()
+}
+
+/// Lattice points strictly inside, by Pick's theorem: `A = I + B / 2 - 1`.
+///
+/// Only holds for simple polygons with lattice corners, for anything else the count means
+/// nothing. `None` where it would go below zero, i.e. for a polygon with no area.
+///
+/// ```
+/// use aoc_2025::libs::poly;
+///
+/// assert_eq!(poly::interior_points(&[(0, 0), (4, 0), (4, 4), (0, 4)]), Some(9));
+/// assert_eq!(poly::interior_points(&[(0, 0), (4, 0)]), None);
+/// ```
+pub fn interior_points(poly: &[Point]) -> Option<u128> {
+    let boundary = boundary_points(poly);
+
+    if boundary == 0 {
+        return Some(0);
+    }
+
+    (twice_area(poly).unsigned_abs() + 2)
+        .checked_sub(boundary)
+        .map(|x| x / 2)
+}
+
+/// Lattice points inside or on the edges, i.e. how many tiles the loop of day 09 covers.
+/// `None` like [`interior_points`].
+///
+/// ```
+/// use aoc_2025::libs::poly;
+///
+/// let sample = [(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)];
+///
+/// assert_eq!(poly::boundary_points(&sample), 30);
+/// assert_eq!(poly::lattice_points(&sample), Some(46));
+/// ```
+pub fn lattice_points(poly: &[Point]) -> Option<u128> {
+    interior_points(poly).map(|x| x + boundary_points(poly))
+}
+
+/// Whether `point` is inside, on an edge, or outside. Any simple polygon works.
+///
+/// ```
+/// use aoc_2025::libs::poly::{self, Location};
+///
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+///
+/// assert_eq!(poly::locate(&square, (2, 2)), Location::Inside);
+/// assert_eq!(poly::locate(&square, (4, 1)), Location::Boundary);
+/// assert_eq!(poly::locate(&square, (5, 1)), Location::Outside);
+/// ```
+pub fn locate(poly: &[Point], (x, y): Point) -> Location {
+    let (x, y) = (x as i128, y as i128);
+    let mut inside = false;
+
+    for ((x1, y1), (x2, y2)) in edges(poly) {
+        let (x1, y1, x2, y2) = (x1 as i128, y1 as i128, x2 as i128, y2 as i128);
+
+        let cross = (x2 - x1) * (y - y1) - (y2 - y1) * (x - x1);
+        let within = x1.min(x2) <= x && x <= x1.max(x2) && y1.min(y2) <= y && y <= y1.max(y2);
+
+        if cross == 0 && within {
+            return Location::Boundary;
+        }
+
+        // a ray to the right, every edge counts on its lower end only.
+        if (y1 > y) != (y2 > y) {
+            // the edge crosses the ray right of the point, the sign flips with its direction.
+            let right = if y2 > y1 { cross > 0 } else { cross < 0 };
+
+            if right {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Inside or on an edge.
+pub fn contains(poly: &[Point], point: Point) -> bool {
+    locate(poly, point) != Location::Outside
+}
+
+/// Whether every lattice point of the rectangle between `a` and `b` is inside a rectilinear
+/// polygon or on its edges, i.e. every tile of it is red or green on day 09.
+///
+/// Tiles, not the region: a rectangle across a slot too narrow to hold a tile still fits.
+pub fn contains_rect(poly: &[Point], a: Point, b: Point) -> bool {
+    let xs = stops(poly.iter().map(|p| p.0), a.0.min(b.0), a.0.max(b.0));
+    let ys = stops(poly.iter().map(|p| p.1), a.1.min(b.1), a.1.max(b.1));
+
+    xs.iter()
+        .all(|&x| ys.iter().all(|&y| contains(poly, (x, y))))
+}
+
+/// Coordinates from `lo` to `hi` standing in for all of them: the ends, every corner coordinate
+/// in between, and one inside each gap. No edge starts or ends within a gap, so its points
+/// are all alike.
+fn stops(coordinates: impl Iterator<Item = i64>, lo: i64, hi: i64) -> Vec<i64> {
+    let mut stops: Vec<i64> = coordinates
+        .filter(|&t| lo < t && t < hi)
+        .chain([lo, hi])
+        .collect();
+
+    stops.sort_unstable();
+    stops.dedup();
+
+    let gaps: Vec<i64> = stops
+        .windows(2)
+        .filter(|x| x[1] - x[0] > 1)
+        .map(|x| x[0] + 1)
+        .collect();
+
+    stops.extend(gaps);
+    stops
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A row of columns standing on the x axis, widths and heights all positive.
+    fn skylines() -> impl Strategy<Value = Vec<Point>> {
+        prop::collection::vec((1_i64..4, 1_i64..6), 1..6).prop_map(|columns| {
+            let mut poly = vec![(0, 0)];
+            let mut x = 0;
+
+            for (width, height) in columns {
+                poly.push((x, height));
+                x += width;
+                poly.push((x, height));
+            }
+
+            poly.push((x, 0));
+            poly
+        })
+    }
+
+    fn box_of(poly: &[Point]) -> (Point, Point) {
+        let xs = poly.iter().map(|x| x.0);
+        let ys = poly.iter().map(|x| x.1);
+
+        (
+            (xs.clone().min().unwrap() - 1, ys.clone().min().unwrap() - 1),
+            (xs.max().unwrap() + 1, ys.max().unwrap() + 1),
+        )
+    }
+
+    /// Every lattice point of the rectangle.
+    fn brute_contains_rect(poly: &[Point], a: Point, b: Point) -> bool {
+        (a.0.min(b.0)..=a.0.max(b.0))
+            .all(|x| (a.1.min(b.1)..=a.1.max(b.1)).all(|y| contains(poly, (x, y))))
+    }
+
+    #[test]
+    fn a_slot_without_tiles_splits_nothing() {
+        // a U, the slot between x = 2 and x = 3 has no tile in it.
+        let u = [
+            (0, 0),
+            (5, 0),
+            (5, 4),
+            (3, 4),
+            (3, 1),
+            (2, 1),
+            (2, 4),
+            (0, 4),
+        ];
+
+        assert!((0..=5).all(|x| contains(&u, (x, 2))));
+        assert_eq!(locate(&u, (2, 2)), Location::Boundary);
+        assert!(contains_rect(&u, (0, 0), (5, 4)));
+        assert!(contains_rect(&u, (2, 2), (3, 2)));
+
+        // one tile wider, and x = 3 is out.
+        let wide = u.map(|(x, y)| if x >= 3 { (x + 1, y) } else { (x, y) });
+
+        assert_eq!(locate(&wide, (3, 2)), Location::Outside);
+        assert!(!contains_rect(&wide, (0, 2), (6, 3)));
+        assert!(contains_rect(&wide, (0, 0), (6, 1)));
+    }
+
+    proptest! {
+        #[test]
+        fn pick_counts_like_checking_every_point(poly in skylines()) {
+            let ((x1, y1), (x2, y2)) = box_of(&poly);
+            let (mut inside, mut boundary) = (0, 0);
+
+            for x in x1..=x2 {
+                for y in y1..=y2 {
+                    match locate(&poly, (x, y)) {
+                        Location::Inside => inside += 1,
+                        Location::Boundary => boundary += 1,
+                        Location::Outside => {}
+                    }
+                }
+            }
+
+            prop_assert_eq!(interior_points(&poly), Some(inside));
+            prop_assert_eq!(boundary_points(&poly), boundary);
+        }
+
+        #[test]
+        fn contains_rect_like_checking_every_point(
+            poly in skylines(),
+            a in (-1_i64..16, -1_i64..7),
+            b in (-1_i64..16, -1_i64..7),
+        ) {
+            prop_assert_eq!(contains_rect(&poly, a, b), brute_contains_rect(&poly, a, b));
+        }
+
+        #[test]
+        fn reversing_flips_the_area_only(poly in skylines(), x in -1_i64..16, y in -1_i64..7) {
+            let reversed: Vec<Point> = poly.iter().rev().copied().collect();
+
+            prop_assert_eq!(twice_area(&reversed), -twice_area(&poly));
+            prop_assert_eq!(locate(&reversed, (x, y)), locate(&poly, (x, y)));
+        }
+    }
+}
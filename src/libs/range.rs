@@ -32,3 +32,55 @@ pub fn minimal_cover(ranges: Vec<RangeInclusive<isize>>) -> Vec<RangeInclusive<i
 
     combined
 }
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    /// Small ranges around small numbers, so they overlap a lot. Sorted by start like
+    /// [`minimal_cover`] wants, none empty.
+    fn ranges() -> impl Strategy<Value = Vec<RangeInclusive<isize>>> {
+        prop::collection::vec((-50_isize..50, 0_isize..20), 1..20).prop_map(|x| {
+            let mut ranges: Vec<RangeInclusive<isize>> =
+                x.into_iter().map(|(a, w)| a..=a + w).collect();
+            ranges.sort_by_key(|x| *x.start());
+            ranges
+        })
+    }
+
+    fn points(ranges: &[RangeInclusive<isize>]) -> BTreeSet<isize> {
+        ranges.iter().flat_map(|x| x.clone()).collect()
+    }
+
+    #[test]
+    fn handles_the_edge() {
+        assert_eq!(minimal_cover(vec![(isize::MAX - 1)..=isize::MAX]).len(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn sorted_and_disjoint(ranges in ranges()) {
+            let cover = minimal_cover(ranges);
+
+            for pair in cover.windows(2) {
+                prop_assert!(pair[0].end() < pair[1].start());
+            }
+        }
+
+        #[test]
+        fn same_points(ranges in ranges()) {
+            let cover = minimal_cover(ranges.clone());
+
+            prop_assert_eq!(points(&cover), points(&ranges));
+        }
+
+        #[test]
+        fn idempotent(ranges in ranges()) {
+            let cover = minimal_cover(ranges);
+
+            prop_assert_eq!(minimal_cover(cover.clone()), cover);
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::{
+    any::Any,
+    fmt::{self, Display, Formatter},
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::libs::{answer::Answer, answers::Answers, bench::format_ns, input, solution::Day};
+
+/// One part of one day against one input file.
+pub struct Job<'a> {
+    pub solver: &'a dyn Day,
+    pub part: u8,
+    /// Input name, i.e. `personal`, used to find the recorded answer.
+    pub input: String,
+    pub path: PathBuf,
+}
+
+/// How a job went.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Status {
+    Ok,
+    /// Solved, but there is nothing recorded to compare with.
+    Unrecorded,
+    Mismatch {
+        expected: String,
+    },
+    Panicked(String),
+    /// The input is missing or malformed, or the child process died.
+    Failed(String),
+    TimedOut(Duration),
+    /// Went over the memory cap, in MB.
+    OutOfMemory(u64),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Ok | Status::Unrecorded)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unrecorded => write!(f, "ok (not recorded)"),
+            Status::Mismatch { expected } => write!(f, "MISMATCH, expected {}", expected),
+            Status::Panicked(message) => write!(f, "PANICKED, {}", message),
+            Status::Failed(message) => write!(f, "FAILED, {}", message),
+            Status::TimedOut(after) => write!(f, "timed out after {}s", after.as_secs_f64()),
+            Status::OutOfMemory(mb) => write!(f, "exceeded {} MB", mb),
+        }
+    }
+}
+
+/// The outcome of one [`Job`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// `None` unless the part finished.
+    pub answer: Option<Answer>,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub status: Status,
+}
+
+/// What a panic carries is usually a string.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(x) = payload.downcast_ref::<&str>() {
+        String::from(*x)
+    } else if let Some(x) = payload.downcast_ref::<String>() {
+        x.clone()
+    } else {
+        String::from("no message")
+    }
+}
+
+impl Report {
+    /// A report of a job that did not get to solve anything.
+    pub fn failed(job: &Job, status: Status) -> Self {
+        Self {
+            day: job.solver.day(),
+            part: job.part,
+            input: job.input.clone(),
+            answer: None,
+            parse: Duration::ZERO,
+            solve: Duration::ZERO,
+            status,
+        }
+    }
+
+    /// Compare a fresh answer with the recorded one.
+    pub fn judge(&mut self, answers: &Answers) {
+        let (Status::Unrecorded, Some(answer)) = (&self.status, &self.answer) else {
+            return;
+        };
+
+        let expected = answers
+            .get(self.day, &self.input, self.part)
+            .and_then(|x| x.answer.clone());
+
+        self.status = match expected {
+            None => Status::Unrecorded,
+            Some(expected) if expected == answer.to_string() => Status::Ok,
+            Some(expected) => Status::Mismatch { expected },
+        };
+    }
+}
+
+/// Run a job in this process, a panicking solver only takes its own job down.
+///
+/// Answers come back [`Status::Unrecorded`], see [`Report::judge`].
+pub fn run_job(job: &Job) -> Report {
+    let mut report = Report::failed(job, Status::Unrecorded);
+
+    let content = match input::read(&job.path) {
+        Ok(content) => content,
+        Err(e) => {
+            report.status = Status::Failed(format!("{}: {}", job.path.display(), e));
+            return report;
+        }
+    };
+
+    let is_sample = input::is_sample(&job.input);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        job.solver.run(&content, is_sample, &[job.part])
+    }));
+
+    let run = match result {
+        Ok(Ok(run)) => run,
+        Ok(Err(e)) => {
+            report.status = Status::Failed(format!("{}: {}", job.path.display(), e));
+            return report;
+        }
+        Err(payload) => {
+            report.status = Status::Panicked(panic_message(payload.as_ref()));
+            return report;
+        }
+    };
+
+    let (_, answer, solve) = run.parts.into_iter().next().expect("Asked for one part.");
+
+    report.answer = Some(answer);
+    report.parse = run.parse;
+    report.solve = solve;
+
+    report
+}
+
+/// Run every job with `run` on `threads` worker threads, then judge against `answers`.
+/// Reports come back in job order.
+///
+/// Panics still go through the panic hook, swap it out to keep stderr quiet.
+pub fn run_all<F>(jobs: &[Job], threads: usize, answers: &Answers, run: F) -> Vec<Report>
+where
+    F: Fn(&Job) -> Report + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(vec![None; jobs.len()]);
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            s.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(job) = jobs.get(i) else {
+                        break;
+                    };
+
+                    let mut report = run(job);
+                    report.judge(answers);
+
+                    reports.lock().expect("A worker died holding the lock?")[i] = Some(report);
+                }
+            });
+        }
+    });
+
+    reports
+        .into_inner()
+        .expect("A worker died holding the lock?")
+        .into_iter()
+        .map(|x| x.expect("Every job gets a report."))
+        .collect()
+}
+
+pub fn to_markdown(reports: &[Report]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Answer | Time | Status |\n\
+         | --: | ---: | -----: | ---: | ------ |\n",
+    );
+
+    for r in reports {
+        let answer = match &r.answer {
+            // grids span several lines, keep the table in one piece.
+            Some(answer) => answer.to_string().replace('\n', "<br>"),
+            None => String::from("-"),
+        };
+
+        table.push_str(&format!(
+            "| {:0>2} | {} | {} | {} | {} |\n",
+            r.day,
+            r.part,
+            answer,
+            format_ns(r.solve.as_nanos() as f64),
+            r.status
+        ));
+    }
+
+    table
+}
+
+/// One line of machine readable output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Where the input came from, a path or `stdin`.
+    pub source: String,
+    pub answer: Option<String>,
+    /// See [`Answer::kind`].
+    pub kind: Option<&'static str>,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    pub status: String,
+    pub failed: bool,
+}
+
+impl Record {
+    pub fn new(report: &Report, source: &str) -> Self {
+        Self {
+            day: report.day,
+            part: report.part,
+            source: source.to_owned(),
+            answer: report.answer.as_ref().map(|x| x.to_string()),
+            kind: report.answer.as_ref().map(|x| x.kind()),
+            parse_ns: report.parse.as_nanos(),
+            solve_ns: report.solve.as_nanos(),
+            status: report.status.to_string(),
+            failed: report.status.is_failure(),
+        }
+    }
+
+    const CSV_HEADER: &str = "day,part,source,answer,kind,parse_ns,solve_ns,status,failed";
+
+    fn csv_fields(&self) -> [String; 9] {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            self.source.clone(),
+            self.answer.clone().unwrap_or_default(),
+            self.kind.unwrap_or_default().to_owned(),
+            self.parse_ns.to_string(),
+            self.solve_ns.to_string(),
+            self.status.clone(),
+            self.failed.to_string(),
+        ]
+    }
+}
+
+/// Quote a field if it needs it, grids span several lines.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// RFC 4180, with a header.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from(Record::CSV_HEADER);
+    csv.push('\n');
+
+    for r in records {
+        let fields: Vec<String> = r.csv_fields().iter().map(|x| csv_field(x)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// One tab separated line per record, no header, line breaks in answers escaped as `\n`.
+/// Good enough for `cut` and `awk`.
+pub fn to_plain(records: &[Record]) -> String {
+    let mut plain = String::new();
+
+    for r in records {
+        let fields: Vec<String> = r
+            .csv_fields()
+            .iter()
+            .map(|x| x.replace('\n', "\\n").replace('\t', " "))
+            .collect();
+
+        plain.push_str(&fields.join("\t"));
+        plain.push('\n');
+    }
+
+    plain
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use crate::libs::{
+        fetch::aoc_test::scratch_dir,
+        solution::{Erased, ParseError, Solution, SolveError},
+    };
+    use std::fs;
+
+    struct Grumpy;
+
+    impl Solution for Grumpy {
+        const DAY: u8 = 42;
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_1(_input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(Answer::from(7))
+        }
+
+        fn part_2(_input: &Self::Input) -> Result<Answer, SolveError> {
+            panic!("Not today.")
+        }
+    }
+
+    #[test]
+    fn keeps_going_after_a_panic() {
+        let dir = scratch_dir("runner");
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = input::path_in(&dir, 42, "personal");
+        fs::write(&path, "whatever\n").unwrap();
+
+        let solver = Erased::<Grumpy>::new();
+        let job = |part: u8, path: PathBuf| Job {
+            solver: &solver,
+            part,
+            input: String::from("personal"),
+            path,
+        };
+
+        let jobs = [
+            job(1, path.clone()),
+            job(2, path.clone()),
+            job(1, dir.join("missing.txt")),
+        ];
+
+        let mut answers = Answers::default();
+        answers.entry(42, "personal", 1).answer = Some(String::from("8"));
+
+        let reports = run_all(&jobs, 2, &answers, run_job);
+        let statuses: Vec<&Status> = reports.iter().map(|x| &x.status).collect();
+
+        assert_eq!(
+            statuses[..2],
+            [
+                &Status::Mismatch {
+                    expected: String::from("8")
+                },
+                &Status::Panicked(String::from("Not today."))
+            ]
+        );
+        assert!(matches!(statuses[2], Status::Failed(_)));
+        assert_eq!(reports[0].answer, Some(Answer::from(7)));
+    }
+
+    #[test]
+    fn quotes_grids_in_csv() {
+        let mut report = Report::failed(
+            &Job {
+                solver: &Erased::<Grumpy>::new(),
+                part: 1,
+                input: String::from("personal"),
+                path: PathBuf::from("a,b.txt"),
+            },
+            Status::Unrecorded,
+        );
+        report.answer = Some(Answer::grid(["#.", ".#"]));
+
+        let records = [Record::new(&report, "a,b.txt")];
+
+        assert_eq!(
+            to_csv(&records),
+            format!(
+                "{}\n42,1,\"a,b.txt\",\"#.\n.#\",grid,0,0,ok (not recorded),false\n",
+                Record::CSV_HEADER
+            )
+        );
+        assert_eq!(
+            to_plain(&records),
+            "42\t1\ta,b.txt\t#.\\n.#\tgrid\t0\t0\tok (not recorded)\tfalse\n"
+        );
+    }
+}
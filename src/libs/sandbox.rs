@@ -0,0 +1,135 @@
+use std::{
+    io::{self, Read},
+    path::Path,
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::libs::runner::{Job, Report, Status};
+
+/// How often a running worker gets checked on.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Caps for one worker, `None` means no cap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory_mb: Option<u64>,
+}
+
+/// Arguments for the hidden `worker` command of the main binary.
+pub fn worker_args(job: &Job, limits: &Limits) -> Vec<String> {
+    let mut args = vec![
+        String::from("worker"),
+        String::from("--day"),
+        job.solver.day().to_string(),
+        String::from("--part"),
+        job.part.to_string(),
+        String::from("--input"),
+        job.input.clone(),
+        String::from("--path"),
+        job.path.display().to_string(),
+    ];
+
+    if let Some(mb) = limits.memory_mb {
+        args.push(String::from("--memory-mb"));
+        args.push(mb.to_string());
+    }
+
+    args
+}
+
+/// Cap the address space of the current process, the worker does this to itself before solving.
+#[cfg(unix)]
+pub fn limit_memory(mb: u64) -> io::Result<()> {
+    let bytes = mb * 1024 * 1024;
+    rlimit::setrlimit(rlimit::Resource::AS, bytes, bytes)
+}
+
+#[cfg(not(unix))]
+pub fn limit_memory(_mb: u64) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Memory caps only work on unix.",
+    ))
+}
+
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut output);
+        }
+
+        output
+    })
+}
+
+/// Wait for the child, or kill it once `timeout` is up. `None` means it got killed.
+fn wait(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+    let start = Instant::now();
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+
+        if timeout.is_some_and(|x| start.elapsed() >= x) {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Run a job in a child process of `exe`, which must understand [`worker_args`].
+///
+/// Hangs and allocation failures only take the child down.
+pub fn run_isolated(exe: &Path, job: &Job, limits: &Limits) -> Report {
+    let child = Command::new(exe)
+        .args(worker_args(job, limits))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => return Report::failed(job, Status::Failed(format!("no worker, {}", e))),
+    };
+
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let status = wait(&mut child, limits.timeout);
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let status = match status {
+        Ok(Some(status)) => status,
+        Ok(None) => {
+            let timeout = limits.timeout.expect("Only killed on timeouts.");
+            return Report::failed(job, Status::TimedOut(timeout));
+        }
+        Err(e) => return Report::failed(job, Status::Failed(format!("lost the worker, {}", e))),
+    };
+
+    if status.success() {
+        return serde_json::from_str(stdout.trim()).unwrap_or_else(|e| {
+            Report::failed(job, Status::Failed(format!("garbled worker output, {}", e)))
+        });
+    }
+
+    // the default allocation error handler says this right before aborting.
+    match limits.memory_mb {
+        Some(mb) if stderr.contains("memory allocation of") => {
+            Report::failed(job, Status::OutOfMemory(mb))
+        }
+        _ => Report::failed(job, Status::Failed(format!("worker {}", status))),
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    mem,
+};
+
+/// A state packed into bits, i.e. one bit per light.
+pub type State = u128;
+
+/// How many states a search may remember before giving up, both directions together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub max_states: usize,
+}
+
+impl Budget {
+    /// Roughly what one remembered state costs, the hash map overhead included.
+    const BYTES_PER_STATE: usize = 2 * mem::size_of::<(State, Step)>();
+
+    pub const fn from_bytes(bytes: usize) -> Self {
+        Self {
+            max_states: bytes / Self::BYTES_PER_STATE,
+        }
+    }
+}
+
+/// The search went over its [`Budget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverBudget {
+    pub visited: usize,
+}
+
+impl Display for OverBudget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Gave up after {} states.", self.visited)
+    }
+}
+
+impl std::error::Error for OverBudget {}
+
+/// Every `(move, state)` next to a state, in one direction.
+type Moves<'a> = &'a dyn Fn(State) -> Vec<(usize, State)>;
+
+/// How a state was first reached: from which state, by which move, how far from the origin.
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    from: State,
+    by: usize,
+    depth: u32,
+}
+
+/// One direction of the search.
+struct Side {
+    seen: HashMap<State, Option<Step>>,
+    frontier: Vec<State>,
+    depth: u32,
+}
+
+impl Side {
+    fn new(origin: State) -> Self {
+        Self {
+            seen: HashMap::from([(origin, None)]),
+            frontier: vec![origin],
+            depth: 0,
+        }
+    }
+
+    fn depth_of(&self, state: State) -> u32 {
+        self.seen[&state].map_or(0, |x| x.depth)
+    }
+
+    /// Moves from `state` back to the origin, in the order they were found.
+    fn walk_back(&self, mut state: State) -> Vec<usize> {
+        let mut moves = vec![];
+
+        while let Some(step) = self.seen[&state] {
+            moves.push(step.by);
+            state = step.from;
+        }
+
+        moves
+    }
+}
+
+/// Shortest sequence of moves from `start` to `goal`, searching from both ends at once.
+///
+/// `forward(s)` lists `(move, next)` for every move out of `s`, `backward(s)` lists
+/// `(move, previous)` for every move that leads into `s`. For moves that undo themselves,
+/// like toggling lights, both are the same function.
+///
+/// `Ok(None)` if the goal is out of reach.
+///
+/// ```
+/// use aoc_2025::libs::search::{self, Budget};
+///
+/// // toggle bit 0, or bits 0 and 1.
+/// let toggles = [0b01, 0b11];
+/// let moves = |s: u128| toggles.iter().enumerate().map(|(i, t)| (i, s ^ t)).collect::<Vec<_>>();
+///
+/// let path = search::bidirectional(0, 0b10, moves, moves, Budget { max_states: 100 });
+///
+/// assert_eq!(path.unwrap().unwrap().len(), 2);
+/// ```
+pub fn bidirectional<F, B>(
+    start: State,
+    goal: State,
+    forward: F,
+    backward: B,
+    budget: Budget,
+) -> Result<Option<Vec<usize>>, OverBudget>
+where
+    F: Fn(State) -> Vec<(usize, State)>,
+    B: Fn(State) -> Vec<(usize, State)>,
+{
+    if start == goal {
+        return Ok(Some(vec![]));
+    }
+
+    let mut ahead = Side::new(start);
+    let mut behind = Side::new(goal);
+
+    while !ahead.frontier.is_empty() && !behind.frontier.is_empty() {
+        // grow the smaller side, that keeps both about the same size.
+        let grow_ahead = ahead.frontier.len() <= behind.frontier.len();
+
+        let (side, other, moves): (&mut Side, &Side, Moves) = if grow_ahead {
+            (&mut ahead, &behind, &forward)
+        } else {
+            (&mut behind, &ahead, &backward)
+        };
+
+        // the best meeting point of this level, (total length, state).
+        let mut best: Option<(u32, State)> = None;
+        let mut next = vec![];
+
+        side.depth += 1;
+
+        for state in mem::take(&mut side.frontier) {
+            for (by, to) in moves(state) {
+                if side.seen.contains_key(&to) {
+                    continue;
+                }
+
+                side.seen.insert(
+                    to,
+                    Some(Step {
+                        from: state,
+                        by,
+                        depth: side.depth,
+                    }),
+                );
+
+                if other.seen.contains_key(&to) {
+                    let length = side.depth + other.depth_of(to);
+
+                    if best.is_none_or(|x| length < x.0) {
+                        best = Some((length, to));
+                    }
+                }
+
+                next.push(to);
+            }
+
+            let visited = side.seen.len() + other.seen.len();
+
+            if visited > budget.max_states {
+                return Err(OverBudget { visited });
+            }
+        }
+
+        side.frontier = next;
+
+        if let Some((_, meet)) = best {
+            let mut path = ahead.walk_back(meet);
+            path.reverse();
+            path.extend(behind.walk_back(meet));
+
+            return Ok(Some(path));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    fn plain_bfs(start: State, goal: State, toggles: &[State]) -> Option<usize> {
+        let mut seen = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(state) = queue.pop_front() {
+            if state == goal {
+                return Some(seen[&state]);
+            }
+
+            for t in toggles {
+                if !seen.contains_key(&(state ^ t)) {
+                    seen.insert(state ^ t, seen[&state] + 1);
+                    queue.push_back(state ^ t);
+                }
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn one_way_moves() {
+        // +1 or *2, from 1 to 100.
+        let forward = |s: State| vec![(0, s + 1), (1, s * 2)];
+        let backward = |s: State| {
+            let mut moves = vec![];
+
+            if s > 0 {
+                moves.push((0, s - 1));
+            }
+
+            if s.is_multiple_of(2) {
+                moves.push((1, s / 2));
+            }
+
+            moves
+        };
+
+        let path = bidirectional(1, 100, forward, backward, Budget { max_states: 10_000 })
+            .unwrap()
+            .unwrap();
+
+        let end = path
+            .iter()
+            .fold(1, |s, &m| if m == 0 { s + 1 } else { s * 2 });
+
+        // 1 2 3 6 12 24 25 50 100.
+        assert_eq!(path.len(), 8);
+        assert_eq!(end, 100);
+    }
+
+    #[test]
+    fn stays_within_budget() {
+        let toggles: Vec<State> = (0..40).map(|x| 1 << x).collect();
+        let moves = |s: State| {
+            toggles
+                .iter()
+                .map(|t| s ^ t)
+                .enumerate()
+                .collect::<Vec<_>>()
+        };
+
+        let result = bidirectional(0, (1 << 40) - 1, moves, moves, Budget { max_states: 1000 });
+
+        assert!(result.is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn as_short_as_plain_bfs(
+            toggles in prop::collection::vec(1_u128..256, 0..8),
+            goal in 0_u128..256,
+        ) {
+            let moves = |s: State| toggles.iter().map(|t| s ^ t).enumerate().collect::<Vec<_>>();
+            let path = bidirectional(0, goal, moves, moves, Budget { max_states: 10_000 }).unwrap();
+
+            prop_assert_eq!(path.as_ref().map(|x| x.len()), plain_bfs(0, goal, &toggles));
+
+            if let Some(path) = path {
+                prop_assert_eq!(path.iter().fold(0, |s, &m| s ^ toggles[m]), goal);
+            }
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use crate::libs::{
+    answer::Answer,
+    bench::{self, Stats, format_ns},
+    generate::{self, Rng},
+    input,
+};
+
+/// The input does not look like what the puzzle promised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// 1-based.
+    pub line: Option<usize>,
+    /// 1-based, counted in characters.
+    pub column: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Blame a line, counted from 0 like `enumerate()` does.
+    pub fn at_line(mut self, index: usize) -> Self {
+        self.line = Some(index + 1);
+        self
+    }
+
+    /// Blame a character of a line, both counted from 0.
+    pub fn at(mut self, line_index: usize, column_index: usize) -> Self {
+        self.line = Some(line_index + 1);
+        self.column = Some(column_index + 1);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The input parsed fine, but a part has no answer for it, i.e. a target out of reach.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Why a [`Day`] gave no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayError {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl From<ParseError> for DayError {
+    fn from(e: ParseError) -> Self {
+        DayError::Parse(e)
+    }
+}
+
+impl From<SolveError> for DayError {
+    fn from(e: SolveError) -> Self {
+        DayError::Solve(e)
+    }
+}
+
+/// Reads after the input, i.e. `input.txt is malformed, line 3: ...`.
+impl Display for DayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::Parse(e) => write!(f, "malformed, {}", e),
+            DayError::Solve(e) => write!(f, "unsolvable, {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DayError {}
+
+/// One day of the calendar.
+///
+/// Parsing happens once, both parts then borrow the parsed input,
+/// which also makes them easy to test on in-memory strings:
+///
+/// ```
+/// use aoc_2025::{
+///     days::day_05::Day05,
+///     libs::{answer::Answer, solution::{self, Solution}},
+/// };
+///
+/// let input = solution::load::<Day05>("3-5\n10-14\n\n1\n5\n", false).unwrap();
+///
+/// assert_eq!(Day05::part_1(&input), Ok(Answer::from(1)));
+/// assert_eq!(Day05::part_2(&input), Ok(Answer::from(8)));
+/// ```
+pub trait Solution {
+    /// Used to find the input files, i.e. `input/day_05_personal.txt`.
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Tweak whatever the puzzle text sets differently for its examples.
+    fn for_sample(_input: &mut Self::Input) {}
+
+    /// Work shared by both parts, done once right after parsing.
+    fn prepare(_input: &mut Self::Input) {}
+
+    /// Errs on inputs that parse but have no answer, i.e. a target out of reach.
+    fn part_1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// See [`Solution::part_1`].
+    fn part_2(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Slow but obviously right, to check [`Solution::part_1`] against.
+    /// Days without one give `None`.
+    fn reference_1(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// See [`Solution::reference_1`].
+    fn reference_2(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// A random input in the exact puzzle format, `size` is roughly how many lines or items.
+    /// Days without a generator give `None`.
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+}
+
+/// Parse, then get the input ready for solving.
+pub fn load<S: Solution>(content: &str, is_sample: bool) -> Result<S::Input, ParseError> {
+    let mut input = S::parse(content)?;
+
+    if is_sample {
+        S::for_sample(&mut input);
+    }
+
+    S::prepare(&mut input);
+
+    Ok(input)
+}
+
+/// Run a part against an already loaded input.
+pub fn part<S: Solution>(input: &S::Input, part: u8) -> Result<Answer, SolveError> {
+    match part {
+        1 => S::part_1(input),
+        2 => S::part_2(input),
+        _ => panic!("There are only two parts, got {}.", part),
+    }
+}
+
+/// The reference answer of a part, see [`Solution::reference_1`].
+pub fn reference<S: Solution>(input: &S::Input, part: u8) -> Option<Answer> {
+    match part {
+        1 => S::reference_1(input),
+        2 => S::reference_2(input),
+        _ => panic!("There are only two parts, got {}.", part),
+    }
+}
+
+/// Load an input file of the day, i.e. `sample` or `personal`.
+///
+/// Panics if the file is missing or malformed, meant for tests and `main`.
+pub fn load_file<S: Solution>(name: &str) -> S::Input {
+    let path = input::path(S::DAY, name);
+    let content = input::read(&path).expect("File read error.");
+
+    load::<S>(&content, input::is_sample(name))
+        .unwrap_or_else(|e| panic!("{} is malformed, {}.", path.display(), e))
+}
+
+/// What a day binary does: solve the personal input and tell how long it took.
+pub fn main<S: Solution>() {
+    let now = Instant::now();
+    let input = load_file::<S>("personal");
+    println!(
+        "Parsing took {}.",
+        format_ns(now.elapsed().as_nanos() as f64)
+    );
+
+    for p in [1, 2] {
+        let now = Instant::now();
+
+        match part::<S>(&input, p) {
+            Ok(answer) => println!("Part {}: {}", p, answer),
+            Err(e) => println!("Part {} has no answer, {}.", p, e),
+        }
+
+        println!(
+            "Running part_{}() took {}.",
+            p,
+            format_ns(now.elapsed().as_nanos() as f64)
+        );
+    }
+}
+
+/// A finished run of some parts of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse: Duration,
+    /// Part number, answer and time taken.
+    pub parts: Vec<(u8, Answer, Duration)>,
+}
+
+/// [`Day::run`] with its own way to parse and solve, i.e. day 10 on another backend.
+pub fn run_with<I>(
+    content: &str,
+    parts: &[u8],
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    solve: impl Fn(&I, u8) -> Result<Answer, SolveError>,
+) -> Result<Run, DayError> {
+    let now = Instant::now();
+    let input = parse(content)?;
+    let parse = now.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&p| {
+            let now = Instant::now();
+            let answer = solve(&input, p)?;
+            Ok((p, answer, now.elapsed()))
+        })
+        .collect::<Result<_, SolveError>>()?;
+
+    Ok(Run { parse, parts })
+}
+
+/// Timings of repeated runs of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRun {
+    pub parse: Stats,
+    /// Part number and timings.
+    pub parts: Vec<(u8, Stats)>,
+}
+
+/// [`Solution`] without the types, so every day fits in one list.
+pub trait Day: Sync {
+    fn day(&self) -> u8;
+
+    /// Only [`Solution::parse`], the answer is thrown away. For fuzzing.
+    fn parse(&self, content: &str) -> Result<(), ParseError>;
+
+    fn solve(&self, content: &str, is_sample: bool, part: u8) -> Result<Answer, DayError>;
+
+    fn run(&self, content: &str, is_sample: bool, parts: &[u8]) -> Result<Run, DayError>;
+
+    fn bench(
+        &self,
+        content: &str,
+        is_sample: bool,
+        warmup: usize,
+        iterations: usize,
+    ) -> Result<BenchRun, DayError>;
+
+    /// See [`Solution::reference_1`].
+    fn reference(
+        &self,
+        content: &str,
+        is_sample: bool,
+        part: u8,
+    ) -> Result<Option<Answer>, ParseError>;
+
+    /// See [`Solution::generate`].
+    fn generate(&self, size: usize, seed: u64) -> Option<String>;
+}
+
+/// Wraps a [`Solution`] into a [`Day`].
+pub struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> Erased<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Erased<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Day for Erased<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, content: &str) -> Result<(), ParseError> {
+        S::parse(content).map(|_| ())
+    }
+
+    fn solve(&self, content: &str, is_sample: bool, p: u8) -> Result<Answer, DayError> {
+        let input = load::<S>(content, is_sample)?;
+        Ok(part::<S>(&input, p)?)
+    }
+
+    fn run(&self, content: &str, is_sample: bool, parts: &[u8]) -> Result<Run, DayError> {
+        run_with(content, parts, |x| load::<S>(x, is_sample), part::<S>)
+    }
+
+    fn bench(
+        &self,
+        content: &str,
+        is_sample: bool,
+        warmup: usize,
+        iterations: usize,
+    ) -> Result<BenchRun, DayError> {
+        let input = load::<S>(content, is_sample)?;
+
+        // an error would only get timed.
+        for p in [1, 2] {
+            part::<S>(&input, p)?;
+        }
+
+        let parse = bench::measure(warmup, iterations, || load::<S>(content, is_sample));
+
+        let parts = [1, 2]
+            .into_iter()
+            .map(|p| {
+                (
+                    p,
+                    bench::measure(warmup, iterations, || part::<S>(&input, p)),
+                )
+            })
+            .collect();
+
+        Ok(BenchRun { parse, parts })
+    }
+
+    fn reference(
+        &self,
+        content: &str,
+        is_sample: bool,
+        p: u8,
+    ) -> Result<Option<Answer>, ParseError> {
+        let input = load::<S>(content, is_sample)?;
+        Ok(reference::<S>(&input, p))
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        S::generate(size, &mut generate::rng(seed))
+    }
+}
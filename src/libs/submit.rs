@@ -0,0 +1,157 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::LazyLock,
+};
+
+use regex::Regex;
+
+use crate::libs::{
+    answers::{Record, Refusal, Verdict},
+    fetch::{self, Client, FetchError},
+};
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answers database already knows how this ends.
+    Refused(Refusal),
+    Fetch(FetchError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(reason) => write!(f, "Not submitting: {}.", reason),
+            SubmitError::Fetch(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<FetchError> for SubmitError {
+    fn from(e: FetchError) -> Self {
+        SubmitError::Fetch(e)
+    }
+}
+
+/// Server reply to a submission.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// The human readable part of the page, without tags.
+    pub message: String,
+}
+
+/// Make sense of the page returned after submitting an answer.
+///
+/// ```
+/// use aoc_2025::libs::{answers::Verdict, submit};
+///
+/// let page = "<main><article><p>That's not the right answer; \
+///             your answer is too high.</p></article></main>";
+///
+/// assert_eq!(submit::parse_reply(page).verdict, Verdict::TooHigh);
+/// ```
+pub fn parse_reply(page: &str) -> Reply {
+    let message = match ARTICLE.captures(page) {
+        Some(article) => TAG.replace_all(&article[1], "").trim().to_owned(),
+        None => TAG.replace_all(page, "").trim().to_owned(),
+    };
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("too high") {
+        Verdict::TooHigh
+    } else if message.contains("too low") {
+        Verdict::TooLow
+    } else if message.contains("answer too recently") {
+        Verdict::Wait
+    } else if message.contains("the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Wrong
+    };
+
+    Reply { verdict, message }
+}
+
+/// Submit an answer, unless `record` already tells it is pointless,
+/// then remember the verdict in `record`.
+pub fn submit(
+    client: &Client,
+    record: &mut Record,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Reply, SubmitError> {
+    record.check(answer).map_err(SubmitError::Refused)?;
+
+    let url = client.url(&format!("/{}/day/{}/answer", year, day));
+    let level = part.to_string();
+    let page = client.post_form(&url, &[("level", &level), ("answer", answer)])?;
+
+    let reply = parse_reply(&page);
+    record.learn(answer, reply.verdict, fetch::now().as_secs());
+
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::libs::fetch::aoc_test::{scratch_dir, stub_server};
+
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. \
+                           Please wait one minute before trying again.</p></article>";
+
+    const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer \
+                           to decorating the North Pole.</p></article>";
+
+    #[test]
+    fn records_every_attempt() {
+        let (url, requests) = stub_server(vec![(200, TOO_LOW), (200, CORRECT)]);
+
+        let client = Client::new(&url, "cookie")
+            .input_dir(&scratch_dir("submit"))
+            .min_interval(Duration::ZERO);
+
+        let mut record = Record::default();
+
+        let reply = submit(&client, &mut record, 2025, 3, 2, "41").unwrap();
+        assert_eq!(reply.verdict, Verdict::TooLow);
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=41"));
+
+        // known to be too low, the server never sees it.
+        assert!(matches!(
+            submit(&client, &mut record, 2025, 3, 2, "40"),
+            Err(SubmitError::Refused(Refusal::NotAbove(_)))
+        ));
+
+        let reply = submit(&client, &mut record, 2025, 3, 2, "42").unwrap();
+        assert_eq!(reply.verdict, Verdict::Correct);
+
+        assert_eq!(record.answer.as_deref(), Some("42"));
+        assert_eq!(record.too_low.as_deref(), Some("41"));
+        assert_eq!(record.attempts.len(), 2);
+    }
+
+    #[test]
+    fn understands_the_waiting_room() {
+        let page = "<article><p>You gave an answer too recently; you have to wait after \
+                    submitting an answer before trying again. You have 34s left to wait.\
+                    </p></article>";
+
+        assert_eq!(parse_reply(page).verdict, Verdict::Wait);
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use crate::{
+    days,
+    libs::{answer::Answer, answers::Answers, input, runner::panic_message},
+};
+
+/// How a freshly computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// Nothing recorded yet, so nothing to compare with.
+    Unrecorded,
+    /// The input could not be read or parsed, or the solver panicked.
+    Failed(String),
+}
+
+/// One part of one input of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: u8,
+    /// `None` if it failed.
+    pub actual: Option<Answer>,
+    pub outcome: Outcome,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:0>2} {} part {}: ",
+            self.day, self.input, self.part
+        )?;
+
+        if let Some(actual) = &self.actual {
+            write!(f, "{}", actual)?;
+        }
+
+        match &self.outcome {
+            Outcome::Match => write!(f, " (ok)"),
+            Outcome::Mismatch { expected } => write!(f, " (MISMATCH, expected {})", expected),
+            Outcome::Unrecorded => write!(f, " (not recorded)"),
+            Outcome::Failed(message) => write!(f, "FAILED, {}", message),
+        }
+    }
+}
+
+/// Solve every input found in `dir` for the given days and compare with `answers`.
+///
+/// A bad input or a panicking solver fails both parts of that input only, the rest still runs.
+pub fn verify_in(dir: &Path, answers: &Answers, days: &[u8]) -> io::Result<Vec<Check>> {
+    let mut checks = vec![];
+
+    for &day in days {
+        for name in input::names_in(dir, day)? {
+            let Some(solver) = days::get(day) else {
+                continue;
+            };
+
+            let path = input::path_in(dir, day, &name);
+
+            let run = input::read(&path)
+                .map_err(|e| format!("{}: {}", path.display(), e))
+                .and_then(|content| {
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        solver.run(&content, input::is_sample(&name), &[1, 2])
+                    }));
+
+                    match result {
+                        Ok(Ok(run)) => Ok(run),
+                        Ok(Err(e)) => Err(format!("{} is {}", path.display(), e)),
+                        Err(payload) => {
+                            Err(format!("panicked, {}", panic_message(payload.as_ref())))
+                        }
+                    }
+                });
+
+            let run = match run {
+                Ok(run) => run,
+                Err(message) => {
+                    for part in [1, 2] {
+                        checks.push(Check {
+                            day,
+                            input: name.clone(),
+                            part,
+                            actual: None,
+                            outcome: Outcome::Failed(message.clone()),
+                        });
+                    }
+
+                    continue;
+                }
+            };
+
+            for (part, actual, _) in run.parts {
+                let expected = answers.get(day, &name, part).and_then(|x| x.answer.clone());
+
+                let outcome = match expected {
+                    None => Outcome::Unrecorded,
+                    Some(expected) if expected == actual.to_string() => Outcome::Match,
+                    Some(expected) => Outcome::Mismatch { expected },
+                };
+
+                checks.push(Check {
+                    day,
+                    input: name.clone(),
+                    part,
+                    actual: Some(actual),
+                    outcome,
+                });
+            }
+        }
+    }
+
+    Ok(checks)
+}
+
+/// Store the computed answers as confirmed ones.
+///
+/// Mismatches are only overwritten with `force`, a confirmed answer should not move silently.
+pub fn record(answers: &mut Answers, checks: &[Check], force: bool) -> usize {
+    let mut count = 0;
+
+    for check in checks {
+        let overwrite = match check.outcome {
+            Outcome::Match | Outcome::Failed(_) => false,
+            Outcome::Mismatch { .. } => force,
+            Outcome::Unrecorded => true,
+        };
+
+        if let (true, Some(actual)) = (overwrite, &check.actual) {
+            answers.entry(check.day, &check.input, check.part).answer = Some(actual.to_string());
+            count += 1;
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod aoc_test {
+    use super::*;
+    use crate::libs::fetch::aoc_test::scratch_dir;
+    use std::fs;
+
+    #[test]
+    fn record_then_verify() {
+        let dir = scratch_dir("verify");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input::path_in(&dir, 5, "sample"), "3-5\n10-14\n\n1\n5\n").unwrap();
+
+        let mut answers = Answers::default();
+        let checks = verify_in(&dir, &answers, &[5]).unwrap();
+
+        assert_eq!(checks.len(), 2);
+        assert!(checks.iter().all(|x| x.outcome == Outcome::Unrecorded));
+        assert_eq!(record(&mut answers, &checks, false), 2);
+
+        let checks = verify_in(&dir, &answers, &[5]).unwrap();
+        assert!(checks.iter().all(|x| x.outcome == Outcome::Match));
+
+        // someone "improved" the solver.
+        answers.entry(5, "sample", 2).answer = Some(String::from("9"));
+
+        let checks = verify_in(&dir, &answers, &[5]).unwrap();
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Mismatch {
+                expected: String::from("9")
+            }
+        );
+
+        assert_eq!(record(&mut answers, &checks, false), 0);
+        assert_eq!(record(&mut answers, &checks, true), 1);
+    }
+
+    #[test]
+    fn a_bad_input_fails_alone() {
+        let dir = scratch_dir("verify-bad");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input::path_in(&dir, 5, "broken"), "3-5\nnope\n").unwrap();
+        fs::write(input::path_in(&dir, 5, "sample"), "3-5\n10-14\n\n1\n5\n").unwrap();
+
+        let checks = verify_in(&dir, &Answers::default(), &[5]).unwrap();
+
+        assert_eq!(checks.len(), 4);
+        assert!(matches!(checks[0].outcome, Outcome::Failed(_)));
+        assert!(matches!(checks[1].outcome, Outcome::Failed(_)));
+        assert_eq!(checks[2].actual, Some(Answer::from(1)));
+        assert_eq!(checks[3].outcome, Outcome::Unrecorded);
+
+        let mut answers = Answers::default();
+        assert_eq!(record(&mut answers, &checks, true), 2);
+    }
+}
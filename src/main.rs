@@ -1,53 +1,1322 @@
-use std::{
-    fs::{self, File},
-    io::Write,
-};
-
-use clap::Parser;
-
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
-    /// The day number.
-    #[arg(long, default_value_t = 1)]
-    day: u8,
-}
-
-fn main() {
-    let args = Args::parse();
-    let day_number = args.day;
-
-    let day_number_leftpad = format!("{:0>2}", day_number);
-    let day_number_leftpad = day_number_leftpad.as_str();
-
-    let input_base_dir = String::from("input/day_");
-    let code_base_dir = String::from("src/bin/day_");
-
-    // Input files.
-
-    println!("Creating input files.");
-
-    let _ = fs::create_dir("input");
-
-    File::create_new(input_base_dir.clone() + day_number_leftpad + "_sample.txt")
-        .expect("Sample input file already exists!");
-
-    File::create_new(input_base_dir.clone() + day_number_leftpad + "_personal.txt")
-        .expect("Personal input file already exists!");
-
-    // Code file.
-
-    println!("Creating code file.");
-
-    let content = fs::read_to_string(code_base_dir.clone() + "00/main.rs")
-        .expect("Unable to read the sample code.");
-
-    fs::create_dir(code_base_dir.clone() + day_number_leftpad)
-        .expect("Unable to create code directory.");
-
-    let mut file = File::create_new(code_base_dir.clone() + day_number_leftpad + "/main.rs")
-        .expect("Unable to create file.");
-
-    file.write_all(content.as_bytes())
-        .expect("Code file write error!");
-}
+use std::{
+    env,
+    error::Error,
+    fs::{self, File},
+    io::Write,
+    ops::Range,
+    panic,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_2025::{
+    days::{
+        self, day_05,
+        day_09::{self, Day09},
+        day_10::{self, Day10},
+    },
+    libs::{
+        answer::Answer,
+        answers::{self, Answers},
+        bench::{self, Measurement},
+        bench_history::{self, Entry},
+        diff,
+        fetch::{self, Client, FetchError, Fetched},
+        ilp, input, runner, sandbox,
+        solution::{self, Solution},
+        submit, verify,
+    },
+};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The day number to scaffold when no command is given.
+    #[arg(long, default_value_t = 1)]
+    day: u8,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download the personal input of a day into `input/`.
+    Fetch {
+        /// The day number.
+        #[arg(long)]
+        day: u8,
+
+        /// The event year.
+        #[arg(long, default_value_t = 2025)]
+        year: u16,
+
+        /// Where the puzzles are served from.
+        #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Download again even if the input is already cached.
+        #[arg(long)]
+        force: bool,
+
+        /// Minimum number of seconds between two requests.
+        #[arg(long, default_value_t = 15)]
+        min_interval: u64,
+    },
+
+    /// Solve a day against any input, or every day at once.
+    Run {
+        /// The day number.
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Run every registered day in parallel and check against `answers.toml`.
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+
+        /// A file to read, or `-` for stdin. Defaults to the personal input.
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Only run this part.
+        #[arg(long)]
+        part: Option<u8>,
+
+        /// The input is an example from the puzzle text, some days solve those differently.
+        /// With `--all`, run the sample inputs.
+        #[arg(long)]
+        sample: bool,
+
+        /// Worker threads for `--all`, defaults to one per core.
+        #[arg(long)]
+        threads: Option<usize>,
+
+        /// With `--all`, run each part in its own process and kill it after this many seconds.
+        #[arg(long, requires = "all")]
+        timeout: Option<f64>,
+
+        /// With `--all`, run each part in its own process capped at this many MB.
+        #[arg(long, requires = "all")]
+        memory_mb: Option<u64>,
+
+        /// `text` is for humans, the others give one record per day and part.
+        #[arg(long, value_enum, default_value_t = RunFormat::Text)]
+        format: RunFormat,
+
+        /// Read the input line by line instead of all at once, for inputs bigger than memory.
+        /// Only day 05 can.
+        #[arg(long, conflicts_with_all = ["all", "sample"])]
+        stream: bool,
+
+        /// Integer program backend of day 10 part 2, defaults to the fastest one built in.
+        #[arg(long, conflicts_with_all = ["all", "stream"])]
+        solver: Option<ilp::Backend>,
+    },
+
+    /// Run a single part for `run --all`, and print the report as JSON.
+    #[command(hide = true)]
+    Worker {
+        #[arg(long)]
+        day: u8,
+
+        #[arg(long)]
+        part: u8,
+
+        /// Input name, i.e. `personal`.
+        #[arg(long)]
+        input: String,
+
+        #[arg(long)]
+        path: PathBuf,
+
+        #[arg(long)]
+        memory_mb: Option<u64>,
+    },
+
+    /// Solve a part against the personal input, then submit the answer.
+    Submit {
+        /// The day number.
+        #[arg(long)]
+        day: u8,
+
+        /// The part number.
+        #[arg(long)]
+        part: u8,
+
+        /// Submit this instead of the computed answer.
+        #[arg(long)]
+        answer: Option<String>,
+
+        /// The event year.
+        #[arg(long, default_value_t = 2025)]
+        year: u16,
+
+        /// Where the answers are posted to.
+        #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Minimum number of seconds between two requests.
+        #[arg(long, default_value_t = 15)]
+        min_interval: u64,
+    },
+
+    /// Make a random input in the exact puzzle format.
+    Gen {
+        /// The day number.
+        #[arg(long)]
+        day: u8,
+
+        /// Roughly how many lines or items.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// Same seed, same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Write here instead of stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Show which buttons to press on every day 10 machine, and check that they work.
+    Explain {
+        /// A file to read, or `-` for stdin. Defaults to the personal input.
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Part 1 is about the lights, part 2 about the joltages.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Integer program backend for part 2, defaults to the fastest one built in.
+        #[arg(long)]
+        solver: Option<ilp::Backend>,
+    },
+
+    /// Find the biggest rectangle inside the day 09 loop.
+    Rect {
+        /// A file to read, or `-` for stdin. Defaults to the personal input.
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Which corners have to be red tiles: `red` (two opposite ones, what part 2 asks),
+        /// `one-red` or `any`.
+        #[arg(long, default_value = "any")]
+        corners: day_09::Corners,
+    },
+
+    /// Draw the day 09 loop and the rectangles of both parts as an SVG picture.
+    Svg {
+        /// A file to read, or `-` for stdin. Defaults to the personal input.
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Write here instead of stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Solve every day 10 machine with each integer program backend built in,
+    /// then report where they disagree and how long each took.
+    Solvers {
+        /// A file to read, or `-` for stdin. Defaults to the personal input.
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Also run the exact backend, which can take exponentially long.
+        #[arg(long)]
+        exact: bool,
+    },
+
+    /// Check the fast parts against the slow reference ones on random inputs.
+    Diff {
+        /// A day number, or `all`.
+        #[arg(long, default_value = "all", value_parser = parse_day_selection)]
+        day: DaySelection,
+
+        /// Only check this part.
+        #[arg(long)]
+        part: Option<u8>,
+
+        /// Random inputs per day and part.
+        #[arg(long, default_value_t = 1000)]
+        cases: u64,
+
+        /// Roughly how many lines or items, keep it small for the references.
+        #[arg(long, default_value_t = 6)]
+        size: usize,
+
+        /// Seed of the first input, the others follow.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Do not save mismatching inputs into `input/`.
+        #[arg(long)]
+        no_save: bool,
+    },
+
+    /// Solve every input of every day and compare against `answers.toml`.
+    Verify {
+        /// Only verify this day.
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Store the answers that are not recorded yet.
+        #[arg(long)]
+        record: bool,
+
+        /// With `--record`, also overwrite recorded answers that changed.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Time every part of one day, or of all of them.
+    #[command(args_conflicts_with_subcommands = true)]
+    Bench {
+        #[command(subcommand)]
+        action: Option<BenchAction>,
+
+        /// A day number, or `all`.
+        #[arg(long, default_value = "all", value_parser = parse_day_selection)]
+        day: DaySelection,
+
+        /// Which input to run against, i.e. `personal` or `sample_2`.
+        #[arg(long, default_value = "personal")]
+        input: String,
+
+        /// Untimed runs before measuring.
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs.
+        #[arg(long, default_value_t = 20)]
+        iterations: usize,
+
+        #[arg(long, value_enum, default_value_t = BenchFormat::Markdown)]
+        format: BenchFormat,
+
+        /// Label of this machine in the history, defaults to the host name.
+        #[arg(long)]
+        machine: Option<String>,
+
+        /// Name this run, so later runs can be compared against it.
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Do not append this run to the history.
+        #[arg(long)]
+        no_history: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum BenchAction {
+    /// Compare the latest run against the previous one, or a named baseline.
+    Compare {
+        /// Compare against the latest run with this name.
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Slowdown of the median, in percent, that counts as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Label of this machine in the history, defaults to the host name.
+        #[arg(long)]
+        machine: Option<String>,
+    },
+}
+
+/// Either a single day or every registered one.
+#[derive(Debug, Clone)]
+struct DaySelection(Vec<u8>);
+
+fn parse_day_selection(s: &str) -> Result<DaySelection, String> {
+    if s == "all" {
+        return Ok(DaySelection(days::numbers()));
+    }
+
+    match s.parse::<u8>() {
+        Ok(day) if days::get(day).is_some() => Ok(DaySelection(vec![day])),
+        _ => Err(format!("expected `all` or one of {:?}", days::numbers())),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum RunFormat {
+    Text,
+    Plain,
+    Json,
+    Csv,
+}
+
+/// Print records in a machine readable `format`.
+fn print_records(records: &[runner::Record], format: RunFormat) {
+    match format {
+        RunFormat::Text => unreachable!("Text is printed by hand."),
+        RunFormat::Plain => print!("{}", runner::to_plain(records)),
+        RunFormat::Csv => print!("{}", runner::to_csv(records)),
+        RunFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(records).expect("Serialization error.")
+        ),
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum BenchFormat {
+    Markdown,
+    Json,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match args.command {
+        None => scaffold(args.day),
+        Some(Command::Fetch {
+            day,
+            year,
+            base_url,
+            force,
+            min_interval,
+        }) => {
+            if let Err(e) = fetch_input(day, year, &base_url, force, min_interval) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        Some(Command::Run {
+            all: true,
+            part,
+            sample,
+            threads,
+            timeout,
+            memory_mb,
+            format,
+            ..
+        }) => {
+            let limits = sandbox::Limits {
+                timeout: timeout.map(Duration::from_secs_f64),
+                memory_mb,
+            };
+
+            let threads = threads.unwrap_or_else(|| {
+                thread::available_parallelism()
+                    .map(|x| x.get())
+                    .unwrap_or(1)
+            });
+
+            match run_all_days(part, sample, threads, &limits, format) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Some(Command::Run {
+            day,
+            input,
+            part,
+            sample,
+            format,
+            stream,
+            solver,
+            ..
+        }) => {
+            let day = day.expect("Either --day or --all.");
+
+            let source = match input {
+                Some(arg) => input::Source::from_arg(&arg),
+                None => input::Source::File(input::path(day, "personal")),
+            };
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+
+            let result = if stream {
+                stream_day(day, &source, &parts, format)
+            } else {
+                run_day(day, &source, &parts, sample, format, solver)
+            };
+
+            if let Err(e) = result {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        Some(Command::Worker {
+            day,
+            part,
+            input,
+            path,
+            memory_mb,
+        }) => {
+            if let Some(mb) = memory_mb {
+                sandbox::limit_memory(mb).expect("Unable to cap memory.");
+            }
+
+            let job = runner::Job {
+                solver: days::get(day).expect("No solver for this day."),
+                part,
+                input,
+                path,
+            };
+
+            // panics end up in the report.
+            panic::set_hook(Box::new(|_| {}));
+            let report = runner::run_job(&job);
+
+            println!(
+                "{}",
+                serde_json::to_string(&report).expect("Serialization error.")
+            );
+        }
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            year,
+            base_url,
+            min_interval,
+        }) => {
+            let answer = answer.unwrap_or_else(|| {
+                let content = input::read(&input::path(day, "personal"))
+                    .expect("Personal input missing, try the fetch command.");
+
+                days::get(day)
+                    .expect("No solver for this day.")
+                    .solve(&content, false, part)
+                    .unwrap_or_else(|e| panic!("Personal input is {}.", e))
+                    .to_string()
+            });
+
+            if let Err(e) = submit_answer(day, part, &answer, year, &base_url, min_interval) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        Some(Command::Gen {
+            day,
+            size,
+            seed,
+            out,
+        }) => {
+            let Some(content) = days::get(day).and_then(|x| x.generate(size, seed)) else {
+                eprintln!("No generator for day {}.", day);
+                process::exit(1);
+            };
+
+            match out {
+                Some(path) => fs::write(path, content).expect("Unable to write the input."),
+                None => print!("{}", content),
+            }
+        }
+        Some(Command::Explain {
+            input,
+            part,
+            solver,
+        }) => {
+            let source = match input {
+                Some(arg) => input::Source::from_arg(&arg),
+                None => input::Source::File(input::path(10, "personal")),
+            };
+
+            match explain_machines(&source, part, solver.unwrap_or_default()) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Some(Command::Rect { input, corners }) => {
+            let source = match input {
+                Some(arg) => input::Source::from_arg(&arg),
+                None => input::Source::File(input::path(9, "personal")),
+            };
+
+            if let Err(e) = largest_rectangle(&source, corners) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        Some(Command::Svg { input, out }) => {
+            let source = match input {
+                Some(arg) => input::Source::from_arg(&arg),
+                None => input::Source::File(input::path(9, "personal")),
+            };
+
+            if let Err(e) = draw_loop(&source, out.as_deref()) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        Some(Command::Solvers { input, exact }) => {
+            let source = match input {
+                Some(arg) => input::Source::from_arg(&arg),
+                None => input::Source::File(input::path(10, "personal")),
+            };
+
+            let backends: Vec<ilp::Backend> = ilp::Backend::available()
+                .into_iter()
+                .filter(|&x| exact || x != ilp::Backend::Exact)
+                .collect();
+
+            match compare_solvers(&source, &backends) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Some(Command::Diff {
+            day,
+            part,
+            cases,
+            size,
+            seed,
+            no_save,
+        }) => match diff_days(&day.0, part, seed..seed + cases, size, !no_save) {
+            Ok(true) => {}
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        Some(Command::Verify { day, record, force }) => match verify_answers(day, record, force) {
+            Ok(true) => {}
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        Some(Command::Bench {
+            action:
+                Some(BenchAction::Compare {
+                    baseline,
+                    threshold,
+                    machine,
+                }),
+            ..
+        }) => {
+            let machine = machine.unwrap_or_else(bench_history::machine_name);
+
+            match compare_bench(&machine, baseline.as_deref(), threshold) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Some(Command::Bench {
+            action: None,
+            day,
+            input,
+            warmup,
+            iterations,
+            format,
+            machine,
+            baseline,
+            no_history,
+        }) => {
+            let run = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards?")
+                .as_millis() as u64;
+
+            let measurements = bench_days(&day.0, &input, warmup, iterations);
+
+            if !no_history {
+                let commit = bench_history::current_commit();
+                let machine = machine.unwrap_or_else(bench_history::machine_name);
+
+                let entries: Vec<Entry> = measurements
+                    .iter()
+                    .map(|x| Entry {
+                        run,
+                        commit: commit.clone(),
+                        machine: machine.clone(),
+                        baseline: baseline.clone(),
+                        measurement: x.clone(),
+                    })
+                    .collect();
+
+                bench_history::append(Path::new(bench_history::HISTORY_FILE), &entries)
+                    .expect("Unable to write the bench history.");
+            }
+
+            match format {
+                BenchFormat::Markdown => print!("{}", bench::to_markdown(&measurements)),
+                BenchFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&measurements).expect("Serialization error.")
+                ),
+            }
+        }
+    }
+}
+
+/// Days only have parts 1 and 2.
+fn check_parts(parts: &[u8]) -> Result<(), Box<dyn Error>> {
+    match parts.iter().find(|x| !matches!(x, 1 | 2)) {
+        Some(part) => Err(format!("There is no part {}.", part).into()),
+        None => Ok(()),
+    }
+}
+
+fn run_day(
+    day: u8,
+    source: &input::Source,
+    parts: &[u8],
+    is_sample: bool,
+    format: RunFormat,
+    backend: Option<ilp::Backend>,
+) -> Result<(), Box<dyn Error>> {
+    let solver = days::get(day).ok_or(format!("No solver for day {}.", day))?;
+
+    check_parts(parts)?;
+
+    if backend.is_some() && day != 10 {
+        return Err(format!(
+            "Day {} has no integer program, only day 10 takes a --solver.",
+            day
+        )
+        .into());
+    }
+
+    let content = source
+        .read()
+        .map_err(|e| format!("Unable to read {}, {}.", source, e))?;
+
+    let run = match backend {
+        Some(backend) => solution::run_with(
+            &content,
+            parts,
+            |x| solution::load::<Day10>(x, is_sample),
+            |x, p| match p {
+                2 => day_10::part_2_with(x, backend),
+                _ => solution::part::<Day10>(x, p),
+            },
+        ),
+        None => solver.run(&content, is_sample, parts),
+    }
+    .map_err(|e| format!("{} is {}.", source, e))?;
+
+    if format != RunFormat::Text {
+        let records: Vec<runner::Record> = run
+            .parts
+            .into_iter()
+            .map(|(part, answer, solve)| {
+                let report = runner::Report {
+                    day,
+                    part,
+                    input: source.to_string(),
+                    answer: Some(answer),
+                    parse: run.parse,
+                    solve,
+                    status: runner::Status::Unrecorded,
+                };
+
+                runner::Record::new(&report, &source.to_string())
+            })
+            .collect();
+
+        print_records(&records, format);
+        return Ok(());
+    }
+
+    println!(
+        "Parsing took {}.",
+        bench::format_ns(run.parse.as_nanos() as f64)
+    );
+
+    for (part, answer, elapsed) in run.parts {
+        println!("Part {}: {}", part, answer);
+        println!(
+            "Running part_{}() took {}.",
+            part,
+            bench::format_ns(elapsed.as_nanos() as f64)
+        );
+    }
+
+    Ok(())
+}
+
+/// [`run_day`] for inputs that do not fit in memory, both parts come out of one pass.
+fn stream_day(
+    day: u8,
+    source: &input::Source,
+    parts: &[u8],
+    format: RunFormat,
+) -> Result<(), Box<dyn Error>> {
+    if day != 5 {
+        return Err(format!("Day {} cannot stream its input, only day 05 can.", day).into());
+    }
+
+    check_parts(parts)?;
+
+    let reader = source
+        .open()
+        .map_err(|e| format!("Unable to read {}, {}.", source, e))?;
+
+    let now = Instant::now();
+    let (fresh, total) =
+        day_05::stream(reader).map_err(|e| format!("{} is malformed, {}.", source, e))?;
+    let elapsed = now.elapsed();
+
+    let answers = [(1, Answer::from(fresh)), (2, Answer::from(total))];
+    let answers: Vec<_> = answers
+        .into_iter()
+        .filter(|(part, _)| parts.contains(part))
+        .collect();
+
+    if format != RunFormat::Text {
+        let records: Vec<runner::Record> = answers
+            .into_iter()
+            .map(|(part, answer)| {
+                let report = runner::Report {
+                    day,
+                    part,
+                    input: source.to_string(),
+                    answer: Some(answer),
+                    parse: Duration::ZERO,
+                    solve: elapsed,
+                    status: runner::Status::Unrecorded,
+                };
+
+                runner::Record::new(&report, &source.to_string())
+            })
+            .collect();
+
+        print_records(&records, format);
+        return Ok(());
+    }
+
+    for (part, answer) in answers {
+        println!("Part {}: {}", part, answer);
+    }
+
+    println!(
+        "Streaming took {}.",
+        bench::format_ns(elapsed.as_nanos() as f64)
+    );
+
+    Ok(())
+}
+
+/// Returns whether every part ran fine and matched its recorded answer.
+///
+/// With any limit set, every part runs in a child process.
+fn run_all_days(
+    part: Option<u8>,
+    sample: bool,
+    threads: usize,
+    limits: &sandbox::Limits,
+    format: RunFormat,
+) -> Result<bool, Box<dyn Error>> {
+    let answers = Answers::load(Path::new(answers::ANSWERS_FILE))?;
+    let name = if sample { "sample" } else { "personal" };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let jobs: Vec<runner::Job> = days::DAYS
+        .iter()
+        .flat_map(|&solver| {
+            parts.iter().map(move |&part| runner::Job {
+                solver,
+                part,
+                input: name.to_owned(),
+                path: input::path(solver.day(), name),
+            })
+        })
+        .collect();
+
+    // the report tells about panics already, no need for a backtrace per solver.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let reports = if *limits == sandbox::Limits::default() {
+        runner::run_all(&jobs, threads, &answers, runner::run_job)
+    } else {
+        let exe = env::current_exe()?;
+        runner::run_all(&jobs, threads, &answers, |job| {
+            sandbox::run_isolated(&exe, job, limits)
+        })
+    };
+    panic::set_hook(hook);
+
+    let failures = reports.iter().filter(|x| x.status.is_failure()).count();
+
+    if format == RunFormat::Text {
+        print!("{}", runner::to_markdown(&reports));
+        println!("{} part(s), {} failure(s).", reports.len(), failures);
+    } else {
+        let records: Vec<runner::Record> = reports
+            .iter()
+            .zip(&jobs)
+            .map(|(report, job)| runner::Record::new(report, &job.path.display().to_string()))
+            .collect();
+
+        print_records(&records, format);
+    }
+
+    Ok(failures == 0)
+}
+
+fn fetch_input(
+    day: u8,
+    year: u16,
+    base_url: &str,
+    force: bool,
+    min_interval: u64,
+) -> Result<(), FetchError> {
+    let session =
+        fetch::session_token(Path::new(fetch::SESSION_FILE)).ok_or(FetchError::MissingSession)?;
+
+    let client = Client::new(base_url, &session).min_interval(Duration::from_secs(min_interval));
+
+    match client.fetch_input(year, day, force)? {
+        Fetched::Cached(path) => println!("Using cached {}.", path.display()),
+        Fetched::Downloaded(path) => println!("Saved {}.", path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit_answer(
+    day: u8,
+    part: u8,
+    answer: &str,
+    year: u16,
+    base_url: &str,
+    min_interval: u64,
+) -> Result<(), Box<dyn Error>> {
+    let session =
+        fetch::session_token(Path::new(fetch::SESSION_FILE)).ok_or(FetchError::MissingSession)?;
+
+    let client = Client::new(base_url, &session).min_interval(Duration::from_secs(min_interval));
+
+    let path = Path::new(answers::ANSWERS_FILE);
+    let mut answers = Answers::load(path)?;
+
+    println!("Submitting {} for day {} part {}.", answer, day, part);
+
+    let result = submit::submit(
+        &client,
+        answers.entry(day, "personal", part),
+        year,
+        day,
+        part,
+        answer,
+    );
+
+    // even a failed request may have been recorded.
+    answers.save(path)?;
+
+    let reply = result?;
+    println!("Verdict: {}.", reply.verdict);
+    println!("{}", reply.message);
+
+    Ok(())
+}
+
+/// Returns whether every machine reached its target.
+fn explain_machines(
+    source: &input::Source,
+    part: u8,
+    backend: ilp::Backend,
+) -> Result<bool, Box<dyn Error>> {
+    let content = source
+        .read()
+        .map_err(|e| format!("Unable to read {}, {}.", source, e))?;
+
+    let machines =
+        Day10::parse(&content).map_err(|e| format!("{} is malformed, {}.", source, e))?;
+
+    let mut total = 0;
+    let mut failures = 0;
+
+    for (i, machine) in machines.iter().enumerate() {
+        let presses = if part == 1 {
+            day_10::fewest_toggles(machine)
+        } else {
+            Ok(day_10::fewest_jolt_presses(machine, backend))
+        };
+
+        print!("Machine {}: ", i + 1);
+
+        let presses = match presses {
+            Ok(Some(presses)) => presses,
+            Ok(None) => {
+                println!("{}\n  no way to reach the target.", machine);
+                failures += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("{}\n  {}", machine, e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        print!("{}", day_10::explain(machine, &presses, part));
+
+        if day_10::verify(machine, &presses, part) {
+            total += presses.iter().map(|&x| x as u64).sum::<u64>();
+        } else {
+            failures += 1;
+        }
+    }
+
+    println!("{} press(es) in total, {} failure(s).", total, failures);
+
+    Ok(failures == 0)
+}
+
+fn largest_rectangle(
+    source: &input::Source,
+    corners: day_09::Corners,
+) -> Result<(), Box<dyn Error>> {
+    let content = source
+        .read()
+        .map_err(|e| format!("Unable to read {}, {}.", source, e))?;
+
+    let tiles = Day09::parse(&content).map_err(|e| format!("{} is malformed, {}.", source, e))?;
+
+    match day_09::Floor::new(&tiles).largest(corners) {
+        Some(rect) => println!("{}, {} tiles.", rect, rect.area()),
+        None => println!("No rectangle fits."),
+    }
+
+    Ok(())
+}
+
+fn draw_loop(source: &input::Source, out: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let content = source
+        .read()
+        .map_err(|e| format!("Unable to read {}, {}.", source, e))?;
+
+    let tiles = Day09::parse(&content).map_err(|e| format!("{} is malformed, {}.", source, e))?;
+    let svg = day_09::svg(&tiles);
+
+    match out {
+        Some(path) => fs::write(path, svg)
+            .map_err(|e| format!("Unable to write {}, {}.", path.display(), e))?,
+        None => print!("{}", svg),
+    }
+
+    Ok(())
+}
+
+/// Returns whether every backend agreed on every machine.
+fn compare_solvers(
+    source: &input::Source,
+    backends: &[ilp::Backend],
+) -> Result<bool, Box<dyn Error>> {
+    let content = source
+        .read()
+        .map_err(|e| format!("Unable to read {}, {}.", source, e))?;
+
+    let machines =
+        Day10::parse(&content).map_err(|e| format!("{} is malformed, {}.", source, e))?;
+
+    let mut elapsed = vec![Duration::ZERO; backends.len()];
+    let mut totals = vec![0; backends.len()];
+    let mut disagreements = 0;
+
+    for (i, machine) in machines.iter().enumerate() {
+        let problem = machine.jolt_problem();
+        let outcomes = ilp::compare(problem, backends);
+
+        for (j, outcome) in outcomes.iter().enumerate() {
+            elapsed[j] += outcome.elapsed;
+            totals[j] += outcome.total().unwrap_or(0);
+        }
+
+        if ilp::agree(problem, &outcomes) {
+            continue;
+        }
+
+        disagreements += 1;
+        println!("Machine {}: {}", i + 1, machine);
+
+        for outcome in &outcomes {
+            let verdict = match &outcome.presses {
+                None => String::from("no presses found"),
+                Some(presses) if !problem.satisfied_by(presses) => {
+                    format!(
+                        "{} press(es), which miss the joltages",
+                        outcome.total().unwrap()
+                    )
+                }
+                Some(_) => format!("{} press(es)", outcome.total().unwrap()),
+            };
+
+            println!("  {}: {}", outcome.backend, verdict);
+        }
+    }
+
+    println!("| Solver | Total | Time |");
+    println!("|---|---|---|");
+
+    for (j, backend) in backends.iter().enumerate() {
+        println!(
+            "| {} | {} | {} |",
+            backend,
+            totals[j],
+            bench::format_ns(elapsed[j].as_nanos() as f64)
+        );
+    }
+
+    println!(
+        "{} machine(s), {} disagreement(s).",
+        machines.len(),
+        disagreements
+    );
+
+    Ok(disagreements == 0)
+}
+
+/// Returns whether every fast part agreed with its reference.
+fn diff_days(
+    days: &[u8],
+    part: Option<u8>,
+    seeds: Range<u64>,
+    size: usize,
+    save: bool,
+) -> Result<bool, Box<dyn Error>> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut mismatches = 0;
+
+    for &day in days {
+        let solver = days::get(day).expect("No solver for this day.");
+
+        for &part in &parts {
+            // a panicking fast part is a mismatch like any other, no need for the backtrace.
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(|_| {}));
+            let mismatch = diff::diff(solver, part, seeds.clone(), size);
+            panic::set_hook(hook);
+
+            let Some(mismatch) = mismatch else {
+                println!("day {:0>2} part {}: ok", day, part);
+                continue;
+            };
+
+            mismatches += 1;
+
+            let fast = match &mismatch.fast {
+                Ok(answer) => answer.to_string(),
+                Err(message) => format!("no answer, {}", message),
+            };
+
+            println!(
+                "day {:0>2} part {}: MISMATCH on seed {}, got {}, expected {}",
+                day, part, mismatch.seed, fast, mismatch.reference
+            );
+            print!("{}", mismatch.input);
+
+            if save {
+                let name = diff::save(
+                    Path::new(input::INPUT_DIR),
+                    Path::new(input::SAMPLES_FILE),
+                    &mismatch,
+                )?;
+
+                println!("Saved as {}.", input::path(day, &name).display());
+            }
+        }
+    }
+
+    Ok(mismatches == 0)
+}
+
+/// Returns whether every recorded answer still matches and nothing failed.
+fn verify_answers(day: Option<u8>, record: bool, force: bool) -> Result<bool, Box<dyn Error>> {
+    let path = Path::new(answers::ANSWERS_FILE);
+    let mut answers = Answers::load(path)?;
+
+    let selected = match day {
+        Some(day) => vec![day],
+        None => days::numbers(),
+    };
+
+    let checks = verify::verify_in(Path::new(input::INPUT_DIR), &answers, &selected)?;
+
+    for check in &checks {
+        println!("{}", check);
+    }
+
+    let mismatches = checks
+        .iter()
+        .filter(|x| matches!(x.outcome, verify::Outcome::Mismatch { .. }))
+        .count();
+
+    let failures = checks
+        .iter()
+        .filter(|x| matches!(x.outcome, verify::Outcome::Failed(_)))
+        .count();
+
+    if record {
+        let count = verify::record(&mut answers, &checks, force);
+        answers.save(path)?;
+
+        println!("Recorded {} answer(s).", count);
+
+        return Ok((force || mismatches == 0) && failures == 0);
+    }
+
+    println!(
+        "{} check(s), {} mismatch(es), {} failure(s).",
+        checks.len(),
+        mismatches,
+        failures
+    );
+
+    Ok(mismatches == 0 && failures == 0)
+}
+
+/// Returns whether nothing got slower.
+fn compare_bench(
+    machine: &str,
+    baseline: Option<&str>,
+    threshold: f64,
+) -> Result<bool, Box<dyn Error>> {
+    let history = bench_history::load(Path::new(bench_history::HISTORY_FILE))?;
+
+    let Some((before, after)) = bench_history::pick_runs(&history, machine, baseline) else {
+        return Err(format!("Not enough bench runs on {} to compare.", machine).into());
+    };
+
+    let comparisons = bench_history::compare(
+        &bench_history::run_of(&history, before),
+        &bench_history::run_of(&history, after),
+        threshold,
+    );
+
+    print!("{}", bench_history::to_markdown(&comparisons));
+
+    Ok(!comparisons.iter().any(|x| x.regressed))
+}
+
+fn bench_days(days: &[u8], name: &str, warmup: usize, iterations: usize) -> Vec<Measurement> {
+    let mut measurements = vec![];
+    let is_sample = input::is_sample(name);
+
+    for &day in days {
+        let solver = days::get(day).expect("No solver for this day.");
+        let path = input::path(day, name);
+
+        let Ok(content) = input::read(&path) else {
+            eprintln!("Skipping day {}, {} is missing.", day, path.display());
+            continue;
+        };
+
+        eprintln!("Benchmarking day {}.", day);
+
+        let run = match solver.bench(&content, is_sample, warmup, iterations) {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("Skipping day {}, {} is {}.", day, path.display(), e);
+                continue;
+            }
+        };
+
+        for (part, solve) in run.parts {
+            measurements.push(Measurement {
+                day,
+                part,
+                input: name.to_owned(),
+                parse: Some(run.parse),
+                solve,
+            });
+        }
+    }
+
+    measurements
+}
+
+fn scaffold(day_number: u8) {
+    let day_number_leftpad = format!("{:0>2}", day_number);
+    let day_number_leftpad = day_number_leftpad.as_str();
+
+    let input_base_dir = String::from("input/day_");
+    let code_base_dir = String::from("src/bin/day_");
+    let solver_base_dir = String::from("src/days/day_");
+
+    // Input files.
+
+    println!("Creating input files.");
+
+    let _ = fs::create_dir("input");
+
+    File::create_new(input_base_dir.clone() + day_number_leftpad + "_sample.txt")
+        .expect("Sample input file already exists!");
+
+    File::create_new(input_base_dir.clone() + day_number_leftpad + "_personal.txt")
+        .expect("Personal input file already exists!");
+
+    // Code file.
+
+    println!("Creating code file.");
+
+    let content = fs::read_to_string(code_base_dir.clone() + "00/main.rs")
+        .expect("Unable to read the sample code.");
+
+    let content = content
+        .replace("day_00", &(String::from("day_") + day_number_leftpad))
+        .replace("Day00", &(String::from("Day") + day_number_leftpad));
+
+    fs::create_dir(code_base_dir.clone() + day_number_leftpad)
+        .expect("Unable to create code directory.");
+
+    let mut file = File::create_new(code_base_dir.clone() + day_number_leftpad + "/main.rs")
+        .expect("Unable to create file.");
+
+    file.write_all(content.as_bytes())
+        .expect("Code file write error!");
+
+    // Solver file.
+
+    println!("Creating solver file.");
+
+    let content = fs::read_to_string(solver_base_dir.clone() + "00.rs")
+        .expect("Unable to read the sample solver.")
+        .replace("Day00", &(String::from("Day") + day_number_leftpad))
+        .replace(
+            "const DAY: u8 = 0;",
+            &format!("const DAY: u8 = {};", day_number),
+        );
+
+    let mut file = File::create_new(solver_base_dir.clone() + day_number_leftpad + ".rs")
+        .expect("Unable to create file.");
+
+    file.write_all(content.as_bytes())
+        .expect("Solver file write error!");
+
+    println!(
+        "Remember to add `pub mod day_{0};` and `day_{0}::Day{0}` to src/days/mod.rs.",
+        day_number_leftpad
+    );
+}
@@ -0,0 +1,26 @@
+//! The fast parts against the reference ones, a quick round of what `diff` does.
+
+use aoc_2025::{days, libs::diff};
+
+#[test]
+fn every_day_has_a_reference() {
+    for day in days::DAYS {
+        let content = day.generate(3, 0).expect("No generator.");
+
+        for part in [1, 2] {
+            let reference = day.reference(&content, false, part).expect("Malformed.");
+            assert!(reference.is_some(), "Day {} part {}.", day.day(), part);
+        }
+    }
+}
+
+#[test]
+fn fast_parts_agree() {
+    for day in days::DAYS {
+        for part in [1, 2] {
+            if let Some(mismatch) = diff::diff(day, part, 0..50, 5) {
+                panic!("{:?}", mismatch);
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Every generator has to produce something its own day can parse and solve.
+
+use aoc_2025::days;
+
+#[test]
+fn generated_inputs_solve() {
+    for day in days::DAYS {
+        for seed in 0..5 {
+            let content = day
+                .generate(8, seed)
+                .unwrap_or_else(|| panic!("No generator for day {}.", day.day()));
+
+            if let Err(e) = day.run(&content, false, &[1, 2]) {
+                panic!(
+                    "Day {} seed {} gave a malformed input, {}:\n{}",
+                    day.day(),
+                    seed,
+                    e,
+                    content
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in days::DAYS {
+        assert_eq!(day.generate(20, 7), day.generate(20, 7));
+        assert_ne!(day.generate(20, 7), day.generate(20, 8));
+    }
+}
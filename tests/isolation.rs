@@ -0,0 +1,89 @@
+//! Runs parts through the hidden `worker` command of the real binary.
+
+use std::{env, fmt::Write, fs, path::PathBuf, time::Duration};
+
+use aoc_2025::{
+    days,
+    libs::{
+        answer::Answer,
+        runner::{Job, Status},
+        sandbox::{self, Limits},
+    },
+};
+
+fn scratch_file(name: &str, content: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-2025-isolation-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join(name);
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn run(day: u8, part: u8, path: PathBuf, limits: Limits) -> (Option<Answer>, Status) {
+    let job = Job {
+        solver: days::get(day).unwrap(),
+        part,
+        input: String::from("personal"),
+        path,
+    };
+
+    let report = sandbox::run_isolated(env!("CARGO_BIN_EXE_aoc-2025").as_ref(), &job, &limits);
+    (report.answer, report.status)
+}
+
+#[test]
+fn solves_in_a_worker() {
+    let path = scratch_file("day_05.txt", "3-5\n10-14\n\n1\n5\n");
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(30)),
+        memory_mb: None,
+    };
+
+    assert_eq!(
+        run(5, 2, path, limits),
+        (Some(Answer::from(8)), Status::Unrecorded)
+    );
+}
+
+#[test]
+fn kills_slow_parts() {
+    // the per-integer loop of day 02 takes ages on this.
+    let path = scratch_file("day_02.txt", "1-999999999999\n");
+    let limits = Limits {
+        timeout: Some(Duration::from_millis(500)),
+        memory_mb: None,
+    };
+
+    assert_eq!(
+        run(2, 1, path, limits),
+        (None, Status::TimedOut(Duration::from_millis(500)))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn caps_memory() {
+    // day 08 keeps every pair of boxes around, that is 8 million of them.
+    let mut content = String::new();
+    let mut seed = 1_u64;
+
+    for _ in 0..4000 {
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % 1_000_000
+        };
+
+        writeln!(content, "{},{},{}", next(), next(), next()).unwrap();
+    }
+
+    let path = scratch_file("day_08.txt", &content);
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(60)),
+        memory_mb: Some(128),
+    };
+
+    assert_eq!(run(8, 1, path, limits), (None, Status::OutOfMemory(128)));
+}
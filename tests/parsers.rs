@@ -0,0 +1,111 @@
+//! Every parser against mangled inputs, a bounded stand-in for the `fuzz/` targets on stable.
+//! Malformed input must give a parse error, never a panic.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc_2025::{
+    days,
+    libs::{generate, input},
+};
+use rand::{RngExt, seq::IndexedRandom};
+
+/// Mangled inputs per day.
+const ITERATIONS: usize = 3000;
+
+/// What puzzle inputs are made of, plus a few things they never contain.
+const PIECES: [&str; 24] = [
+    "0", "1", "9", "-", ",", "\n", " ", "\r\n", "#", ".", "@", "^", "S", "+", "*", "L", "R", "(",
+    ")", "[", "]", "{", "}", "é",
+];
+
+fn mangle(content: &str, rng: &mut generate::Rng) -> String {
+    let mut chars: Vec<char> = content.chars().collect();
+
+    for _ in 0..rng.random_range(1..=4) {
+        let at = rng.random_range(0..=chars.len());
+
+        match rng.random_range(0..5) {
+            // cut a chunk out.
+            0 => {
+                let end = rng.random_range(at..=chars.len().min(at + 8));
+                chars.drain(at..end);
+            }
+            1 => {
+                let piece = PIECES.choose(rng).expect("Not empty.");
+                chars.splice(at..at, piece.chars());
+            }
+            2 => chars.truncate(at),
+            // a huge number.
+            3 => {
+                chars.splice(at..at, "99999999999999999999".chars());
+            }
+            _ => {
+                let end = rng.random_range(at..=chars.len().min(at + 16));
+                let copy: Vec<char> = chars[at..end].to_vec();
+                chars.splice(at..at, copy);
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+#[test]
+fn parsers_never_panic() {
+    let mut rng = generate::rng(0);
+
+    for day in days::DAYS {
+        let mut seeds = vec![String::new(), String::from("\n"), String::from(" ")];
+        seeds.extend(input::read(&input::path(day.day(), "sample")).ok());
+        seeds.extend(day.generate(4, 0));
+
+        for i in 0..ITERATIONS {
+            let content = mangle(&seeds[i % seeds.len()], &mut rng);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| day.parse(&content)));
+
+            assert!(
+                result.is_ok(),
+                "Day {} panicked on {:?}.",
+                day.day(),
+                content
+            );
+        }
+    }
+}
+
+#[test]
+fn rejects_what_the_parts_cannot_handle() {
+    let cases = [
+        (1, "L-5\n"),
+        (1, "R9223372036854775800\n"),
+        (3, "12\n\n34\n"),
+        (3, "90\n"),
+        (6, "1 2\n3\n* +\n"),
+        (6, "1234567890\n*\n"),
+        (8, "1,2,3\n"),
+        (8, "1,2,99999999999\n4,5,6\n"),
+        (8, "-9223372036854775808,0,0\n1,2,3\n"),
+        (
+            8,
+            "-1000000000,-1000000000,-1000000000\n1000000000,1000000000,1000000000\n",
+        ),
+        (9, "0,0\n1,1\n"),
+        (9, "0,0\n2,0\n2,2\n1,3\n0,2\n"),
+        (9, "-9223372036854775808,0\n9223372036854775807,0\n"),
+        (10, "[.#] (0,2) {1,2}\n"),
+        (10, "[.#] (0,1) {1,2,3}\n"),
+        (10, ".# (0,1) {1,2}\n"),
+        (10, "[.#] 0,1 {1,2}\n"),
+    ];
+
+    for (day, content) in cases {
+        let day = days::get(day).expect("No solver for this day.");
+        assert!(day.parse(content).is_err(), "{:?}", content);
+    }
+
+    // one button too many for the reference to count in bits.
+    let buttons = vec!["(0)"; 65].join(" ");
+    let content = format!("[#] {} {{1}}\n", buttons);
+
+    assert!(days::get(10).unwrap().parse(&content).is_err());
+}
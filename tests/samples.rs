@@ -0,0 +1,15 @@
+//! One test case per sample input and part, generated from `input/samples.toml` by `build.rs`.
+
+use aoc_2025::{days, libs::input};
+
+fn check(day: u8, name: &str, part: u8, expected: &str) {
+    let content = input::read(&input::path(day, name)).expect("Sample file missing.");
+    let actual = days::get(day)
+        .expect("No solver for this day.")
+        .solve(&content, input::is_sample(name), part)
+        .expect("Sample is malformed.");
+
+    assert_eq!(actual.to_string(), expected);
+}
+
+include!(concat!(env!("OUT_DIR"), "/samples.rs"));